@@ -0,0 +1,224 @@
+use crate::pb::protocol;
+use crate::pb::protocol::transaction::contract::ContractType;
+use crate::pb::sf::substreams::tron::v1::trc10_asset::FrozenSupply;
+use crate::pb::sf::substreams::tron::v1::{Transactions, Trc10Asset, Trc10Assets};
+use crate::pb::sf::tron::r#type::v1::Transaction;
+use crate::utils::{decoded_contracts, tron_address_to_base58};
+use substreams::store::{StoreGet, StoreGetProto, StoreNew, StoreSet, StoreSetProto};
+
+/// Keeps every TRC10 asset as issued, by issuer. An account can only issue a single asset,
+/// which is how an `UpdateAssetContract` designates the asset it changes.
+#[substreams::handlers::store]
+fn store_trc10_issues(transactions: Transactions, store: StoreSetProto<Trc10Asset>) {
+    let block_number = transactions.clock.unwrap_or_default().number;
+
+    for (ordinal, asset) in transactions
+        .transactions
+        .iter()
+        .flat_map(|transaction| issued_assets(transaction, block_number))
+        .enumerate()
+    {
+        store.set(ordinal as u64, issuer_key(&asset.owner), &asset);
+    }
+}
+
+#[substreams::handlers::map]
+fn map_trc10_assets(
+    transactions: Transactions,
+    issues: StoreGetProto<Trc10Asset>,
+) -> Result<Trc10Assets, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+    let mut assets = Vec::new();
+
+    for transaction in &transactions.transactions {
+        assets.extend(issued_assets(transaction, clock.number));
+
+        for contract in decoded_contracts::<protocol::UpdateAssetContract>(
+            transaction,
+            ContractType::UpdateAssetContract,
+        ) {
+            // Assets issued before ids were introduced are not kept
+            let owner = tron_address_to_base58(&contract.owner_address);
+            let Some(mut asset) = issues.get_last(issuer_key(&owner)) else {
+                continue;
+            };
+
+            apply_update(&mut asset, &contract, clock.number);
+            assets.push(asset);
+        }
+    }
+
+    Ok(Trc10Assets {
+        assets,
+        clock: Some(clock),
+    })
+}
+
+/// Keeps every TRC10 asset in its latest state, by asset id.
+#[substreams::handlers::store]
+fn store_trc10_assets(assets: Trc10Assets, store: StoreSetProto<Trc10Asset>) {
+    for (ordinal, asset) in assets.assets.iter().enumerate() {
+        store.set(ordinal as u64, asset_key(&asset.id), asset);
+    }
+}
+
+/// Returns the TRC10 assets issued by the `AssetIssueContract`s of a transaction. The asset id is
+/// assigned by the chain and reported in the transaction info.
+fn issued_assets(transaction: &Transaction, block_number: u64) -> Vec<Trc10Asset> {
+    let asset_issue_id = transaction
+        .info
+        .as_ref()
+        .map(|info| info.asset_issue_id.as_str())
+        .unwrap_or_default();
+
+    decoded_contracts::<protocol::AssetIssueContract>(transaction, ContractType::AssetIssueContract)
+        .filter_map(|contract| {
+            let id = if contract.id.is_empty() {
+                asset_issue_id.to_string()
+            } else {
+                contract.id
+            };
+            // Assets issued before ids were introduced are only known by name
+            if id.is_empty() {
+                return None;
+            }
+
+            Some(Trc10Asset {
+                id,
+                owner: tron_address_to_base58(&contract.owner_address),
+                name: String::from_utf8_lossy(&contract.name).into_owned(),
+                abbr: String::from_utf8_lossy(&contract.abbr).into_owned(),
+                precision: contract.precision,
+                total_supply: contract.total_supply,
+                frozen_supply: contract
+                    .frozen_supply
+                    .iter()
+                    .map(|frozen| FrozenSupply {
+                        frozen_amount: frozen.frozen_amount,
+                        frozen_days: frozen.frozen_days,
+                    })
+                    .collect(),
+                url: String::from_utf8_lossy(&contract.url).into_owned(),
+                description: String::from_utf8_lossy(&contract.description).into_owned(),
+                free_asset_net_limit: contract.free_asset_net_limit,
+                public_free_asset_net_limit: contract.public_free_asset_net_limit,
+                issued_block_number: block_number,
+                updated_block_number: block_number,
+                trx_num: contract.trx_num,
+                num: contract.num,
+            })
+        })
+        .collect()
+}
+
+/// Applies an `UpdateAssetContract` to an asset. An update always replaces all the updatable fields.
+fn apply_update(
+    asset: &mut Trc10Asset,
+    contract: &protocol::UpdateAssetContract,
+    block_number: u64,
+) {
+    asset.url = String::from_utf8_lossy(&contract.url).into_owned();
+    asset.description = String::from_utf8_lossy(&contract.description).into_owned();
+    asset.free_asset_net_limit = contract.new_limit;
+    asset.public_free_asset_net_limit = contract.new_public_limit;
+    asset.updated_block_number = block_number;
+}
+
+pub fn asset_key(id: &str) -> String {
+    format!("asset:{}", id)
+}
+
+fn issuer_key(owner: &str) -> String {
+    format!("issuer:{}", owner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+    use prost_types::Any;
+
+    fn issue_transaction(contract_id: &str, asset_issue_id: &str) -> Transaction {
+        let contract = protocol::AssetIssueContract {
+            id: contract_id.to_string(),
+            owner_address: vec![0x41; 21],
+            name: b"Token".to_vec(),
+            precision: 6,
+            total_supply: 1_000_000,
+            trx_num: 1,
+            num: 10,
+            ..Default::default()
+        };
+        Transaction {
+            contracts: vec![protocol::transaction::Contract {
+                r#type: ContractType::AssetIssueContract as i32,
+                parameter: Some(Any {
+                    type_url: "type.googleapis.com/protocol.AssetIssueContract".to_string(),
+                    value: contract.encode_to_vec(),
+                }),
+                ..Default::default()
+            }],
+            info: Some(protocol::TransactionInfo {
+                asset_issue_id: asset_issue_id.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn issued_asset_ids() {
+        let ids = |transaction: &Transaction| -> Vec<String> {
+            issued_assets(transaction, 42)
+                .into_iter()
+                .map(|asset| asset.id)
+                .collect()
+        };
+
+        assert_eq!(ids(&issue_transaction("1000001", "1002000")), ["1000001"]);
+        assert_eq!(ids(&issue_transaction("", "1002000")), ["1002000"]);
+        // Issued before ids were introduced
+        assert!(ids(&issue_transaction("", "")).is_empty());
+
+        let asset = issued_assets(&issue_transaction("", "1002000"), 42).remove(0);
+        assert_eq!(asset.owner, tron_address_to_base58(&[0x41; 21]));
+        assert_eq!(asset.name, "Token");
+        assert_eq!((asset.trx_num, asset.num), (1, 10));
+        assert_eq!(asset.issued_block_number, 42);
+        assert_eq!(asset.updated_block_number, 42);
+    }
+
+    #[test]
+    fn update_replaces_updatable_fields() {
+        let mut asset = Trc10Asset {
+            id: "1002000".to_string(),
+            name: "Token".to_string(),
+            url: "https://old.example".to_string(),
+            description: "old".to_string(),
+            free_asset_net_limit: 100,
+            public_free_asset_net_limit: 1_000,
+            issued_block_number: 1,
+            updated_block_number: 1,
+            ..Default::default()
+        };
+
+        apply_update(
+            &mut asset,
+            &protocol::UpdateAssetContract {
+                url: b"https://new.example".to_vec(),
+                new_limit: 200,
+                ..Default::default()
+            },
+            42,
+        );
+
+        assert_eq!(asset.url, "https://new.example");
+        // An empty description replaces the previous one
+        assert_eq!(asset.description, "");
+        assert_eq!(asset.free_asset_net_limit, 200);
+        assert_eq!(asset.public_free_asset_net_limit, 0);
+        assert_eq!(asset.name, "Token");
+        assert_eq!(asset.issued_block_number, 1);
+        assert_eq!(asset.updated_block_number, 42);
+    }
+}
@@ -0,0 +1,507 @@
+/// Unit of the value of a chain parameter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterUnit {
+    /// Feature switch, 1 when enabled.
+    Flag,
+    Sun,
+    SunPerBandwidth,
+    SunPerEnergy,
+    Milliseconds,
+    Days,
+    Blocks,
+    Energy,
+    Bandwidth,
+    Bytes,
+    /// Plain number, e.g. a ratio or a multiplier.
+    Number,
+}
+
+impl ParameterUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ParameterUnit::Flag => "flag",
+            ParameterUnit::Sun => "sun",
+            ParameterUnit::SunPerBandwidth => "sun/bandwidth",
+            ParameterUnit::SunPerEnergy => "sun/energy",
+            ParameterUnit::Milliseconds => "ms",
+            ParameterUnit::Days => "days",
+            ParameterUnit::Blocks => "blocks",
+            ParameterUnit::Energy => "energy",
+            ParameterUnit::Bandwidth => "bandwidth",
+            ParameterUnit::Bytes => "bytes",
+            ParameterUnit::Number => "number",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChainParameter {
+    /// Id of the parameter, the key of `ProposalCreateContract.parameters`.
+    pub id: i64,
+    /// Name of the parameter, e.g. `getEnergyFee`.
+    pub name: &'static str,
+    pub unit: ParameterUnit,
+    pub description: &'static str,
+}
+
+/// Chain parameters that can be changed by a proposal, named as by the `getchainparameters` API
+/// of a node and sorted by id. Ids retired by the protocol are left out.
+pub const CHAIN_PARAMETERS: &[ChainParameter] = &[
+    ChainParameter {
+        id: 0,
+        name: "getMaintenanceTimeInterval",
+        unit: ParameterUnit::Milliseconds,
+        description: "Interval between two maintenances, at which votes are counted and witnesses elected.",
+    },
+    ChainParameter {
+        id: 1,
+        name: "getAccountUpgradeCost",
+        unit: ParameterUnit::Sun,
+        description: "Cost of upgrading an account to a witness (super representative candidate).",
+    },
+    ChainParameter {
+        id: 2,
+        name: "getCreateAccountFee",
+        unit: ParameterUnit::Sun,
+        description: "Fee to create an account.",
+    },
+    ChainParameter {
+        id: 3,
+        name: "getTransactionFee",
+        unit: ParameterUnit::SunPerBandwidth,
+        description: "Price of a bandwidth point burned when the free and staked bandwidth are exhausted.",
+    },
+    ChainParameter {
+        id: 4,
+        name: "getAssetIssueFee",
+        unit: ParameterUnit::Sun,
+        description: "Fee to issue a TRC10 asset.",
+    },
+    ChainParameter {
+        id: 5,
+        name: "getWitnessPayPerBlock",
+        unit: ParameterUnit::Sun,
+        description: "Reward of a super representative per block produced.",
+    },
+    ChainParameter {
+        id: 6,
+        name: "getWitnessStandbyAllowance",
+        unit: ParameterUnit::Sun,
+        description: "Total allowance shared by the top 127 witnesses per maintenance period.",
+    },
+    ChainParameter {
+        id: 7,
+        name: "getCreateNewAccountFeeInSystemContract",
+        unit: ParameterUnit::Sun,
+        description: "Fee to create an account through a system contract.",
+    },
+    ChainParameter {
+        id: 8,
+        name: "getCreateNewAccountBandwidthRate",
+        unit: ParameterUnit::Number,
+        description: "Bandwidth consumed to create an account, relative to its size.",
+    },
+    ChainParameter {
+        id: 9,
+        name: "getAllowCreationOfContracts",
+        unit: ParameterUnit::Flag,
+        description: "Enables the deployment of smart contracts.",
+    },
+    ChainParameter {
+        id: 10,
+        name: "getRemoveThePowerOfTheGr",
+        unit: ParameterUnit::Flag,
+        description: "Removes the initial votes of the genesis representatives.",
+    },
+    ChainParameter {
+        id: 11,
+        name: "getEnergyFee",
+        unit: ParameterUnit::SunPerEnergy,
+        description: "Price of an energy unit burned when the staked energy is exhausted.",
+    },
+    ChainParameter {
+        id: 12,
+        name: "getExchangeCreateFee",
+        unit: ParameterUnit::Sun,
+        description: "Fee to create a Bancor exchange.",
+    },
+    ChainParameter {
+        id: 13,
+        name: "getMaxCpuTimeOfOneTx",
+        unit: ParameterUnit::Milliseconds,
+        description: "Maximum execution time of a smart contract call.",
+    },
+    ChainParameter {
+        id: 14,
+        name: "getAllowUpdateAccountName",
+        unit: ParameterUnit::Flag,
+        description: "Allows accounts to change their name.",
+    },
+    ChainParameter {
+        id: 15,
+        name: "getAllowSameTokenName",
+        unit: ParameterUnit::Flag,
+        description: "Allows TRC10 assets to share a name, identifying them by id.",
+    },
+    ChainParameter {
+        id: 16,
+        name: "getAllowDelegateResource",
+        unit: ParameterUnit::Flag,
+        description: "Enables the delegation of resources to other accounts.",
+    },
+    ChainParameter {
+        id: 17,
+        name: "getTotalEnergyLimit",
+        unit: ParameterUnit::Energy,
+        description: "Total energy shared by the stakers per day.",
+    },
+    ChainParameter {
+        id: 18,
+        name: "getAllowTvmTransferTrc10",
+        unit: ParameterUnit::Flag,
+        description: "Enables TRC10 transfers from smart contracts.",
+    },
+    ChainParameter {
+        id: 19,
+        name: "getTotalEnergyCurrentLimit",
+        unit: ParameterUnit::Energy,
+        description: "Current total energy shared by the stakers per day, as adjusted by the adaptive energy.",
+    },
+    ChainParameter {
+        id: 20,
+        name: "getAllowMultiSign",
+        unit: ParameterUnit::Flag,
+        description: "Enables account permissions and multi-signature.",
+    },
+    ChainParameter {
+        id: 21,
+        name: "getAllowAdaptiveEnergy",
+        unit: ParameterUnit::Flag,
+        description: "Enables the adaptive adjustment of the total energy limit.",
+    },
+    ChainParameter {
+        id: 22,
+        name: "getUpdateAccountPermissionFee",
+        unit: ParameterUnit::Sun,
+        description: "Fee to update the permissions of an account.",
+    },
+    ChainParameter {
+        id: 23,
+        name: "getMultiSignFee",
+        unit: ParameterUnit::Sun,
+        description: "Fee of a multi-signature transaction.",
+    },
+    ChainParameter {
+        id: 24,
+        name: "getAllowProtoFilterNum",
+        unit: ParameterUnit::Flag,
+        description: "Enables the protobuf number filter.",
+    },
+    ChainParameter {
+        id: 25,
+        name: "getAllowAccountStateRoot",
+        unit: ParameterUnit::Flag,
+        description: "Enables the account state root.",
+    },
+    ChainParameter {
+        id: 26,
+        name: "getAllowTvmConstantinople",
+        unit: ParameterUnit::Flag,
+        description: "Enables the Constantinople instructions in the TVM.",
+    },
+    ChainParameter {
+        id: 29,
+        name: "getAdaptiveResourceLimitMultiplier",
+        unit: ParameterUnit::Number,
+        description: "Maximum multiplier of the total energy limit under the adaptive energy.",
+    },
+    ChainParameter {
+        id: 30,
+        name: "getChangeDelegation",
+        unit: ParameterUnit::Flag,
+        description: "Enables the brokerage of witnesses, sharing voting rewards with the voters.",
+    },
+    ChainParameter {
+        id: 31,
+        name: "getWitness127PayPerBlock",
+        unit: ParameterUnit::Sun,
+        description: "Voting reward shared by the top 127 witnesses per block.",
+    },
+    ChainParameter {
+        id: 32,
+        name: "getAllowTvmSolidity059",
+        unit: ParameterUnit::Flag,
+        description: "Enables the Solidity 0.5.9 instructions in the TVM.",
+    },
+    ChainParameter {
+        id: 33,
+        name: "getAdaptiveResourceLimitTargetRatio",
+        unit: ParameterUnit::Number,
+        description: "Target ratio of energy usage under the adaptive energy.",
+    },
+    ChainParameter {
+        id: 35,
+        name: "getForbidTransferToContract",
+        unit: ParameterUnit::Flag,
+        description: "Forbids TRX and TRC10 transfers to smart contracts.",
+    },
+    ChainParameter {
+        id: 39,
+        name: "getAllowShieldedTRC20Transaction",
+        unit: ParameterUnit::Flag,
+        description: "Enables shielded TRC20 transactions.",
+    },
+    ChainParameter {
+        id: 40,
+        name: "getAllowPBFT",
+        unit: ParameterUnit::Flag,
+        description: "Enables the PBFT consensus messages.",
+    },
+    ChainParameter {
+        id: 41,
+        name: "getAllowTvmIstanbul",
+        unit: ParameterUnit::Flag,
+        description: "Enables the Istanbul instructions in the TVM.",
+    },
+    ChainParameter {
+        id: 44,
+        name: "getAllowMarketTransaction",
+        unit: ParameterUnit::Flag,
+        description: "Enables the on-chain market of TRC10 assets.",
+    },
+    ChainParameter {
+        id: 45,
+        name: "getMarketSellFee",
+        unit: ParameterUnit::Sun,
+        description: "Fee to place a market order.",
+    },
+    ChainParameter {
+        id: 46,
+        name: "getMarketCancelFee",
+        unit: ParameterUnit::Sun,
+        description: "Fee to cancel a market order.",
+    },
+    ChainParameter {
+        id: 47,
+        name: "getMaxFeeLimit",
+        unit: ParameterUnit::Sun,
+        description: "Maximum fee limit of a smart contract call.",
+    },
+    ChainParameter {
+        id: 48,
+        name: "getAllowTransactionFeePool",
+        unit: ParameterUnit::Flag,
+        description: "Enables the transaction fee pool.",
+    },
+    ChainParameter {
+        id: 49,
+        name: "getAllowBlackHoleOptimization",
+        unit: ParameterUnit::Flag,
+        description: "Burns fees instead of sending them to the black hole account.",
+    },
+    ChainParameter {
+        id: 51,
+        name: "getAllowNewResourceModel",
+        unit: ParameterUnit::Flag,
+        description: "Enables the TRON power resource model.",
+    },
+    ChainParameter {
+        id: 52,
+        name: "getAllowTvmFreeze",
+        unit: ParameterUnit::Flag,
+        description: "Enables staking from smart contracts.",
+    },
+    ChainParameter {
+        id: 53,
+        name: "getAllowAccountAssetOptimization",
+        unit: ParameterUnit::Flag,
+        description: "Enables the account asset storage optimization.",
+    },
+    ChainParameter {
+        id: 59,
+        name: "getAllowTvmVote",
+        unit: ParameterUnit::Flag,
+        description: "Enables voting from smart contracts.",
+    },
+    ChainParameter {
+        id: 60,
+        name: "getAllowTvmCompatibleEvm",
+        unit: ParameterUnit::Flag,
+        description: "Enables the EVM compatibility of the TVM.",
+    },
+    ChainParameter {
+        id: 61,
+        name: "getFreeNetLimit",
+        unit: ParameterUnit::Bandwidth,
+        description: "Free bandwidth of every account per day.",
+    },
+    ChainParameter {
+        id: 62,
+        name: "getTotalNetLimit",
+        unit: ParameterUnit::Bandwidth,
+        description: "Total bandwidth shared by the stakers per day.",
+    },
+    ChainParameter {
+        id: 63,
+        name: "getAllowTvmLondon",
+        unit: ParameterUnit::Flag,
+        description: "Enables the London instructions in the TVM.",
+    },
+    ChainParameter {
+        id: 65,
+        name: "getAllowHigherLimitForMaxCpuTimeOfOneTx",
+        unit: ParameterUnit::Flag,
+        description: "Raises the allowed range of the maximum execution time of a smart contract call.",
+    },
+    ChainParameter {
+        id: 66,
+        name: "getAllowAssetOptimization",
+        unit: ParameterUnit::Flag,
+        description: "Enables the TRC10 asset storage optimization.",
+    },
+    ChainParameter {
+        id: 67,
+        name: "getAllowNewReward",
+        unit: ParameterUnit::Flag,
+        description: "Enables the new voting reward algorithm.",
+    },
+    ChainParameter {
+        id: 68,
+        name: "getMemoFee",
+        unit: ParameterUnit::Sun,
+        description: "Fee of a transaction carrying a memo.",
+    },
+    ChainParameter {
+        id: 69,
+        name: "getAllowDelegateOptimization",
+        unit: ParameterUnit::Flag,
+        description: "Enables the resource delegation storage optimization.",
+    },
+    ChainParameter {
+        id: 70,
+        name: "getUnfreezeDelayDays",
+        unit: ParameterUnit::Days,
+        description: "Waiting period between a Stake 2.0 unfreeze and the withdrawal of the funds.",
+    },
+    ChainParameter {
+        id: 71,
+        name: "getAllowOptimizedReturnValueOfChainId",
+        unit: ParameterUnit::Flag,
+        description: "Makes the CHAINID instruction return the genesis block id.",
+    },
+    ChainParameter {
+        id: 72,
+        name: "getAllowDynamicEnergy",
+        unit: ParameterUnit::Flag,
+        description: "Enables the dynamic energy model for popular contracts.",
+    },
+    ChainParameter {
+        id: 73,
+        name: "getDynamicEnergyThreshold",
+        unit: ParameterUnit::Energy,
+        description: "Energy used by a contract per maintenance period above which its energy factor increases.",
+    },
+    ChainParameter {
+        id: 74,
+        name: "getDynamicEnergyIncreaseFactor",
+        unit: ParameterUnit::Number,
+        description: "Increase of the energy factor of a contract per maintenance period, in ten thousandths.",
+    },
+    ChainParameter {
+        id: 75,
+        name: "getDynamicEnergyMaxFactor",
+        unit: ParameterUnit::Number,
+        description: "Maximum energy factor of a contract, in ten thousandths.",
+    },
+    ChainParameter {
+        id: 76,
+        name: "getAllowTvmShangHai",
+        unit: ParameterUnit::Flag,
+        description: "Enables the Shanghai instructions in the TVM.",
+    },
+    ChainParameter {
+        id: 77,
+        name: "getAllowCancelAllUnfreezeV2",
+        unit: ParameterUnit::Flag,
+        description: "Enables the cancellation of all pending Stake 2.0 unfreezes.",
+    },
+    ChainParameter {
+        id: 78,
+        name: "getMaxDelegateLockPeriod",
+        unit: ParameterUnit::Blocks,
+        description: "Maximum lock period of a resource delegation.",
+    },
+    ChainParameter {
+        id: 79,
+        name: "getAllowOldRewardOpt",
+        unit: ParameterUnit::Flag,
+        description: "Enables the optimization of the old voting reward computation.",
+    },
+    ChainParameter {
+        id: 81,
+        name: "getAllowEnergyAdjustment",
+        unit: ParameterUnit::Flag,
+        description: "Enables the energy adjustment of some TVM instructions.",
+    },
+    ChainParameter {
+        id: 82,
+        name: "getMaxCreateAccountTxSize",
+        unit: ParameterUnit::Bytes,
+        description: "Maximum size of a transaction creating an account.",
+    },
+    ChainParameter {
+        id: 83,
+        name: "getAllowTvmCancun",
+        unit: ParameterUnit::Flag,
+        description: "Enables the Cancun instructions in the TVM.",
+    },
+    ChainParameter {
+        id: 87,
+        name: "getAllowStrictMath",
+        unit: ParameterUnit::Flag,
+        description: "Enables strict math in the TVM.",
+    },
+    ChainParameter {
+        id: 88,
+        name: "getConsensusLogicOptimization",
+        unit: ParameterUnit::Flag,
+        description: "Enables the consensus logic optimization.",
+    },
+    ChainParameter {
+        id: 89,
+        name: "getAllowTvmBlob",
+        unit: ParameterUnit::Flag,
+        description: "Enables the blob instructions in the TVM.",
+    },
+];
+
+/// Returns the chain parameter with the given id, if known.
+pub fn chain_parameter(id: i64) -> Option<&'static ChainParameter> {
+    CHAIN_PARAMETERS
+        .binary_search_by_key(&id, |parameter| parameter.id)
+        .ok()
+        .map(|index| &CHAIN_PARAMETERS[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chain_parameters_sorted_by_id() {
+        assert!(CHAIN_PARAMETERS
+            .windows(2)
+            .all(|pair| pair[0].id < pair[1].id));
+    }
+
+    #[test]
+    fn chain_parameter_lookup() {
+        let energy_fee = chain_parameter(11).unwrap();
+        assert_eq!(energy_fee.name, "getEnergyFee");
+        assert_eq!(energy_fee.unit, ParameterUnit::SunPerEnergy);
+
+        assert_eq!(chain_parameter(70).unwrap().name, "getUnfreezeDelayDays");
+        // Retired shielded transaction parameter
+        assert_eq!(chain_parameter(27), None);
+    }
+}
@@ -0,0 +1,185 @@
+use crate::pb::protocol;
+use crate::pb::protocol::transaction::contract::ContractType;
+use crate::pb::sf::substreams::tron::v1::contract_setting_change::{
+    Change, ClearAbi, UpdateEnergyLimit, UpdateSetting,
+};
+use crate::pb::sf::substreams::tron::v1::{
+    ContractDeployment, ContractDeployments, ContractSettingChange, ContractSettingChanges,
+    Transactions,
+};
+use crate::pb::sf::substreams::v1::Clock;
+use crate::pb::sf::tron::r#type::v1::Transaction;
+use crate::utils::{
+    contract_execution_failed, decode_param, decoded_contracts, tron_address_to_base58,
+};
+use sha2::{Digest, Sha256};
+
+#[substreams::handlers::map]
+fn map_contract_deployments(
+    transactions: Transactions,
+) -> Result<ContractDeployments, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+
+    Ok(ContractDeployments {
+        deployments: transactions
+            .transactions
+            .iter()
+            .flat_map(|transaction| contract_deployments(transaction, &clock))
+            .collect(),
+        clock: Some(clock),
+    })
+}
+
+/// Returns the contracts deployed by the `CreateSmartContract`s of a transaction. A creation that
+/// reverted or ran out of energy is still part of the block, but deploys nothing.
+fn contract_deployments(transaction: &Transaction, clock: &Clock) -> Vec<ContractDeployment> {
+    if contract_execution_failed(transaction) {
+        return Vec::new();
+    }
+
+    // The deployed address is only known once executed, and reported in the transaction info
+    let contract_address = transaction
+        .info
+        .as_ref()
+        .filter(|info| !info.contract_address.is_empty())
+        .map(|info| tron_address_to_base58(&info.contract_address))
+        .unwrap_or_default();
+
+    decoded_contracts::<protocol::CreateSmartContract>(
+        transaction,
+        ContractType::CreateSmartContract,
+    )
+    .map(|contract| {
+        let new_contract = contract.new_contract.unwrap_or_default();
+
+        ContractDeployment {
+            txid: hex::encode(&transaction.txid),
+            block_number: clock.number,
+            block_time: clock.timestamp,
+            deployer: tron_address_to_base58(&contract.owner_address),
+            contract_address: contract_address.clone(),
+            name: new_contract.name,
+            bytecode_hash: hex::encode(Sha256::digest(&new_contract.bytecode)),
+            abi: new_contract.abi,
+            consume_user_resource_percent: new_contract.consume_user_resource_percent,
+            origin_energy_limit: new_contract.origin_energy_limit,
+        }
+    })
+    .collect()
+}
+
+#[substreams::handlers::map]
+fn map_contract_setting_changes(
+    transactions: Transactions,
+) -> Result<ContractSettingChanges, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+    let mut changes = Vec::new();
+
+    for transaction in &transactions.transactions {
+        for contract in &transaction.contracts {
+            let Some(ref parameter) = contract.parameter else {
+                continue;
+            };
+
+            let decoded = match ContractType::try_from(contract.r#type) {
+                Ok(ContractType::UpdateSettingContract) => {
+                    decode_param::<protocol::UpdateSettingContract>(parameter).map(|c| {
+                        let change = Change::UpdateSetting(UpdateSetting {
+                            consume_user_resource_percent: c.consume_user_resource_percent,
+                        });
+                        (c.owner_address, c.contract_address, change)
+                    })
+                }
+                Ok(ContractType::UpdateEnergyLimitContract) => {
+                    decode_param::<protocol::UpdateEnergyLimitContract>(parameter).map(|c| {
+                        let change = Change::UpdateEnergyLimit(UpdateEnergyLimit {
+                            origin_energy_limit: c.origin_energy_limit,
+                        });
+                        (c.owner_address, c.contract_address, change)
+                    })
+                }
+                Ok(ContractType::ClearAbiContract) => {
+                    decode_param::<protocol::ClearAbiContract>(parameter).map(|c| {
+                        (
+                            c.owner_address,
+                            c.contract_address,
+                            Change::ClearAbi(ClearAbi {}),
+                        )
+                    })
+                }
+                _ => None,
+            };
+
+            if let Some((owner_address, contract_address, change)) = decoded {
+                changes.push(ContractSettingChange {
+                    txid: hex::encode(&transaction.txid),
+                    block_number: clock.number,
+                    block_time: clock.timestamp,
+                    contract_address: tron_address_to_base58(&contract_address),
+                    owner: tron_address_to_base58(&owner_address),
+                    change: Some(change),
+                });
+            }
+        }
+    }
+
+    Ok(ContractSettingChanges {
+        changes,
+        clock: Some(clock),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pb::protocol::transaction::result::ContractResult;
+    use prost::Message;
+    use prost_types::Any;
+
+    fn creation(result: ContractResult) -> Transaction {
+        let contract = protocol::CreateSmartContract {
+            owner_address: vec![0x41; 21],
+            new_contract: Some(protocol::SmartContract {
+                name: "Token".to_string(),
+                bytecode: vec![0x60, 0x80],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        Transaction {
+            contracts: vec![protocol::transaction::Contract {
+                r#type: ContractType::CreateSmartContract as i32,
+                parameter: Some(Any {
+                    type_url: "type.googleapis.com/protocol.CreateSmartContract".to_string(),
+                    value: contract.encode_to_vec(),
+                }),
+                ..Default::default()
+            }],
+            info: Some(protocol::TransactionInfo {
+                contract_address: vec![0x42; 21],
+                receipt: Some(protocol::ResourceReceipt {
+                    result: result as i32,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn failed_creations_deploy_nothing() {
+        let deployments =
+            contract_deployments(&creation(ContractResult::Success), &Clock::default());
+        assert_eq!(deployments.len(), 1);
+        assert_eq!(deployments[0].name, "Token");
+        assert_eq!(
+            deployments[0].contract_address,
+            tron_address_to_base58(&[0x42; 21])
+        );
+
+        for result in [ContractResult::Revert, ContractResult::OutOfEnergy] {
+            assert!(contract_deployments(&creation(result), &Clock::default()).is_empty());
+        }
+    }
+}
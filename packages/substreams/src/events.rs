@@ -0,0 +1,32 @@
+use crate::pb::sf::substreams::tron::v1::{Event, Events, Transactions};
+use crate::utils::evm_address_to_base58;
+
+#[substreams::handlers::map]
+fn map_events(transactions: Transactions) -> Result<Events, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+    let mut events = Vec::new();
+
+    for transaction in &transactions.transactions {
+        let Some(ref info) = transaction.info else {
+            continue;
+        };
+
+        for (log_index, log) in info.log.iter().enumerate() {
+            events.push(Event {
+                txid: hex::encode(&transaction.txid),
+                block_number: clock.number,
+                block_time: clock.timestamp,
+                address: evm_address_to_base58(&log.address),
+                topics: log.topics.iter().map(hex::encode).collect(),
+                data: hex::encode(&log.data),
+                log_index: log_index as u32,
+                ordinal: events.len() as u64,
+            });
+        }
+    }
+
+    Ok(Events {
+        events,
+        clock: Some(clock),
+    })
+}
@@ -0,0 +1,249 @@
+use crate::pb::protocol;
+use crate::pb::protocol::transaction::contract::ContractType;
+use crate::pb::sf::substreams::tron::v1::exchange_event::{self, Create, Event, Inject, Withdraw};
+use crate::pb::sf::substreams::tron::v1::{ExchangeEvent, ExchangeEvents, Transactions};
+use crate::pb::sf::substreams::v1::Clock;
+use crate::pb::sf::tron::r#type::v1::Transaction;
+use crate::utils::{base58_to_tron_address, decode_param, tron_address_to_base58};
+use substreams::store::{StoreNew, StoreSet, StoreSetProto};
+
+#[substreams::handlers::map]
+fn map_exchange_events(
+    transactions: Transactions,
+) -> Result<ExchangeEvents, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+
+    Ok(ExchangeEvents {
+        events: transactions
+            .transactions
+            .iter()
+            .flat_map(|transaction| exchange_events(transaction, &clock))
+            .collect(),
+        clock: Some(clock),
+    })
+}
+
+/// Returns the exchange operations of a transaction, joined with the amounts realised by the
+/// protocol as reported in the transaction info.
+fn exchange_events(transaction: &Transaction, clock: &Clock) -> Vec<ExchangeEvent> {
+    let mut events = Vec::new();
+
+    let info = transaction.info.as_ref();
+    let exchange_id = info.map_or(0, |info| info.exchange_id);
+    let inject_another_amount = info.map_or(0, |info| info.exchange_inject_another_amount);
+    let withdraw_another_amount = info.map_or(0, |info| info.exchange_withdraw_another_amount);
+    let received_amount = info.map_or(0, |info| info.exchange_received_amount);
+
+    for contract in &transaction.contracts {
+        let Some(ref parameter) = contract.parameter else {
+            continue;
+        };
+
+        let decoded = match ContractType::try_from(contract.r#type) {
+            Ok(ContractType::ExchangeCreateContract) => {
+                decode_param::<protocol::ExchangeCreateContract>(parameter).map(|c| {
+                    let event = Event::Create(Create {
+                        first_token_id: token_id(&c.first_token_id),
+                        first_token_balance: c.first_token_balance,
+                        second_token_id: token_id(&c.second_token_id),
+                        second_token_balance: c.second_token_balance,
+                    });
+                    (c.owner_address, exchange_id, event)
+                })
+            }
+            Ok(ContractType::ExchangeInjectContract) => {
+                decode_param::<protocol::ExchangeInjectContract>(parameter).map(|c| {
+                    let event = Event::Inject(Inject {
+                        token_id: token_id(&c.token_id),
+                        quant: c.quant,
+                        another_amount: inject_another_amount,
+                    });
+                    (c.owner_address, c.exchange_id, event)
+                })
+            }
+            Ok(ContractType::ExchangeWithdrawContract) => {
+                decode_param::<protocol::ExchangeWithdrawContract>(parameter).map(|c| {
+                    let event = Event::Withdraw(Withdraw {
+                        token_id: token_id(&c.token_id),
+                        quant: c.quant,
+                        another_amount: withdraw_another_amount,
+                    });
+                    (c.owner_address, c.exchange_id, event)
+                })
+            }
+            Ok(ContractType::ExchangeTransactionContract) => {
+                decode_param::<protocol::ExchangeTransactionContract>(parameter).map(|c| {
+                    let event = Event::Transaction(exchange_event::Transaction {
+                        token_id: token_id(&c.token_id),
+                        quant: c.quant,
+                        expected: c.expected,
+                        received_amount,
+                    });
+                    (c.owner_address, c.exchange_id, event)
+                })
+            }
+            _ => None,
+        };
+
+        if let Some((owner_address, exchange_id, event)) = decoded {
+            events.push(ExchangeEvent {
+                txid: hex::encode(&transaction.txid),
+                block_number: clock.number,
+                block_time: clock.timestamp,
+                owner: tron_address_to_base58(&owner_address),
+                exchange_id,
+                event: Some(event),
+            });
+        }
+    }
+
+    events
+}
+
+/// Keeps every exchange as it was created, with its token pair and initial balances.
+#[substreams::handlers::store]
+fn store_exchanges(events: ExchangeEvents, store: StoreSetProto<protocol::Exchange>) {
+    for (ordinal, event) in events.events.into_iter().enumerate() {
+        let Some(Event::Create(create)) = event.event else {
+            continue;
+        };
+
+        let create_time = event.block_time.map_or(0, |timestamp| timestamp.seconds);
+        store.set(
+            ordinal as u64,
+            exchange_key(event.exchange_id),
+            &protocol::Exchange {
+                exchange_id: event.exchange_id,
+                creator_address: base58_to_tron_address(&event.owner).unwrap_or_default(),
+                create_time: create_time * 1000,
+                first_token_id: create.first_token_id.into_bytes(),
+                first_token_balance: create.first_token_balance,
+                second_token_id: create.second_token_id.into_bytes(),
+                second_token_balance: create.second_token_balance,
+            },
+        );
+    }
+}
+
+pub fn exchange_key(exchange_id: i64) -> String {
+    format!("exchange:{}", exchange_id)
+}
+
+fn token_id(token_id: &[u8]) -> String {
+    String::from_utf8_lossy(token_id).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+    use prost_types::Any;
+
+    fn contract<T: Message>(
+        contract_type: ContractType,
+        contract: &T,
+    ) -> protocol::transaction::Contract {
+        protocol::transaction::Contract {
+            r#type: contract_type as i32,
+            parameter: Some(Any {
+                type_url: format!(
+                    "type.googleapis.com/protocol.{}",
+                    contract_type.as_str_name()
+                ),
+                value: contract.encode_to_vec(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn amounts_joined_from_transaction_info() {
+        let owner = vec![0x41; 21];
+        let transaction = Transaction {
+            contracts: vec![
+                contract(
+                    ContractType::ExchangeCreateContract,
+                    &protocol::ExchangeCreateContract {
+                        owner_address: owner.clone(),
+                        first_token_id: b"_".to_vec(),
+                        first_token_balance: 100,
+                        second_token_id: b"1002000".to_vec(),
+                        second_token_balance: 200,
+                    },
+                ),
+                contract(
+                    ContractType::ExchangeInjectContract,
+                    &protocol::ExchangeInjectContract {
+                        owner_address: owner.clone(),
+                        exchange_id: 3,
+                        token_id: b"_".to_vec(),
+                        quant: 10,
+                    },
+                ),
+                contract(
+                    ContractType::ExchangeWithdrawContract,
+                    &protocol::ExchangeWithdrawContract {
+                        owner_address: owner.clone(),
+                        exchange_id: 3,
+                        token_id: b"_".to_vec(),
+                        quant: 20,
+                    },
+                ),
+                contract(
+                    ContractType::ExchangeTransactionContract,
+                    &protocol::ExchangeTransactionContract {
+                        owner_address: owner.clone(),
+                        exchange_id: 3,
+                        token_id: b"_".to_vec(),
+                        quant: 30,
+                        expected: 1,
+                    },
+                ),
+            ],
+            info: Some(protocol::TransactionInfo {
+                exchange_id: 7,
+                exchange_inject_another_amount: 11,
+                exchange_withdraw_another_amount: 22,
+                exchange_received_amount: 33,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let events = exchange_events(&transaction, &Clock::default());
+        assert!(events
+            .iter()
+            .all(|event| event.owner == tron_address_to_base58(&owner)));
+        let ids: Vec<_> = events.iter().map(|event| event.exchange_id).collect();
+        assert_eq!(ids, [7, 3, 3, 3]);
+
+        let events: Vec<_> = events.into_iter().filter_map(|event| event.event).collect();
+        assert_eq!(
+            events,
+            [
+                Event::Create(Create {
+                    first_token_id: "_".to_string(),
+                    first_token_balance: 100,
+                    second_token_id: "1002000".to_string(),
+                    second_token_balance: 200,
+                }),
+                Event::Inject(Inject {
+                    token_id: "_".to_string(),
+                    quant: 10,
+                    another_amount: 11,
+                }),
+                Event::Withdraw(Withdraw {
+                    token_id: "_".to_string(),
+                    quant: 20,
+                    another_amount: 22,
+                }),
+                Event::Transaction(exchange_event::Transaction {
+                    token_id: "_".to_string(),
+                    quant: 30,
+                    expected: 1,
+                    received_amount: 33,
+                }),
+            ]
+        );
+    }
+}
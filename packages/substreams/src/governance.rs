@@ -0,0 +1,384 @@
+use crate::chain_parameters::chain_parameter;
+use crate::pb::protocol;
+use crate::pb::protocol::proposal::State;
+use crate::pb::protocol::transaction::contract::ContractType;
+use crate::pb::sf::substreams::tron::v1::proposal_event::{Approve, Create, Delete, Event};
+use crate::pb::sf::substreams::tron::v1::proposal_transition::Kind;
+use crate::pb::sf::substreams::tron::v1::{
+    ProposalEvent, ProposalEvents, ProposalParameter, ProposalTransition, ProposalTransitions,
+    Transactions, WitnessRanking,
+};
+use crate::pb::sf::substreams::v1::Clock;
+use crate::utils::{
+    base58_to_tron_address, decode_param, decoded_contracts, tron_address_to_base58,
+};
+use crate::witnesses::{maintenance_boundary, MAINTENANCE_INTERVAL_SECONDS};
+use std::collections::HashMap;
+use substreams::store::{
+    Appender, DeltaInt64, Deltas, StoreAdd, StoreAddInt64, StoreAppend, StoreGet, StoreGetArray,
+    StoreGetInt64, StoreGetProto, StoreGetString, StoreNew, StoreSet, StoreSetProto,
+    StoreSetString,
+};
+
+/// Default value of the `getProposalExpireTime` chain parameter, the minimum lifetime of a proposal.
+const PROPOSAL_EXPIRE_SECONDS: i64 = 3 * 24 * 60 * 60;
+
+const PROPOSAL_COUNT_KEY: &str = "proposal_count";
+const ACTIVE_WITNESSES_KEY: &str = "active_witnesses";
+
+/// Keeps the number of proposals created, which is also the id of the latest proposal.
+#[substreams::handlers::store]
+fn store_proposal_count(transactions: Transactions, store: StoreAddInt64) {
+    for transaction in &transactions.transactions {
+        let created = decoded_contracts::<protocol::ProposalCreateContract>(
+            transaction,
+            ContractType::ProposalCreateContract,
+        )
+        .count();
+
+        if created > 0 {
+            store.add(0, PROPOSAL_COUNT_KEY, created as i64);
+        }
+    }
+}
+
+#[substreams::handlers::map]
+fn map_proposal_events(
+    transactions: Transactions,
+    counts: Deltas<DeltaInt64>,
+) -> Result<ProposalEvents, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+    let now = clock.timestamp.map_or(0, |timestamp| timestamp.seconds);
+    // Proposal ids are sequential, the first creation of the block follows the count of the previous block
+    let mut latest_id = counts.deltas.first().map_or(0, |delta| delta.old_value);
+    let mut events = Vec::new();
+
+    for transaction in &transactions.transactions {
+        for contract in &transaction.contracts {
+            let Some(ref parameter) = contract.parameter else {
+                continue;
+            };
+
+            let decoded = match ContractType::try_from(contract.r#type) {
+                Ok(ContractType::ProposalCreateContract) => {
+                    decode_param::<protocol::ProposalCreateContract>(parameter).map(|c| {
+                        latest_id += 1;
+                        let event = Event::Create(Create {
+                            parameters: proposal_parameters(&c.parameters),
+                            expiration_time: Some(prost_types::Timestamp {
+                                seconds: expiration_time(now),
+                                nanos: 0,
+                            }),
+                        });
+                        (c.owner_address, latest_id, event)
+                    })
+                }
+                Ok(ContractType::ProposalApproveContract) => {
+                    decode_param::<protocol::ProposalApproveContract>(parameter).map(|c| {
+                        let event = Event::Approve(Approve {
+                            approve: c.is_add_approval,
+                        });
+                        (c.owner_address, c.proposal_id, event)
+                    })
+                }
+                Ok(ContractType::ProposalDeleteContract) => {
+                    decode_param::<protocol::ProposalDeleteContract>(parameter)
+                        .map(|c| (c.owner_address, c.proposal_id, Event::Delete(Delete {})))
+                }
+                _ => None,
+            };
+
+            if let Some((owner_address, proposal_id, event)) = decoded {
+                events.push(ProposalEvent {
+                    txid: hex::encode(&transaction.txid),
+                    block_number: clock.number,
+                    block_time: clock.timestamp,
+                    owner: tron_address_to_base58(&owner_address),
+                    proposal_id,
+                    event: Some(event),
+                });
+            }
+        }
+    }
+
+    Ok(ProposalEvents {
+        events,
+        clock: Some(clock),
+    })
+}
+
+/// Keeps every proposal as it was created, pending and without approvals.
+#[substreams::handlers::store]
+fn store_proposal_creations(events: ProposalEvents, store: StoreSetProto<protocol::Proposal>) {
+    for (ordinal, event) in events.events.into_iter().enumerate() {
+        let Some(Event::Create(create)) = event.event else {
+            continue;
+        };
+
+        let create_time = event.block_time.map_or(0, |timestamp| timestamp.seconds);
+        let expiration_time = create
+            .expiration_time
+            .map_or(0, |timestamp| timestamp.seconds);
+        store.set(
+            ordinal as u64,
+            proposal_key(event.proposal_id),
+            &protocol::Proposal {
+                proposal_id: event.proposal_id,
+                proposer_address: base58_to_tron_address(&event.owner).unwrap_or_default(),
+                parameters: create
+                    .parameters
+                    .iter()
+                    .map(|parameter| (parameter.id, parameter.value))
+                    .collect(),
+                expiration_time: expiration_time * 1000,
+                create_time: create_time * 1000,
+                approvals: Vec::new(),
+                state: State::Pending as i32,
+            },
+        );
+    }
+}
+
+/// Keeps the approvals, approval removals and deletion of every proposal, in order, as
+/// `approve:<witness>`, `disapprove:<witness>` and `delete` entries.
+#[substreams::handlers::store]
+fn store_proposal_log(events: ProposalEvents, store: StoreAppend<String>) {
+    for (ordinal, event) in events.events.iter().enumerate() {
+        let entry = match event.event {
+            Some(Event::Approve(Approve { approve: true })) => format!("approve:{}", event.owner),
+            Some(Event::Approve(Approve { approve: false })) => {
+                format!("disapprove:{}", event.owner)
+            }
+            Some(Event::Delete(_)) => "delete".to_string(),
+            _ => continue,
+        };
+        store.append(ordinal as u64, proposal_key(event.proposal_id), entry);
+    }
+}
+
+/// Keeps the super representatives elected at the latest maintenance, comma separated.
+#[substreams::handlers::store]
+fn store_active_witnesses(ranking: WitnessRanking, store: StoreSetString) {
+    if ranking.witnesses.is_empty() {
+        return;
+    }
+
+    let active: Vec<String> = ranking
+        .witnesses
+        .iter()
+        .filter(|witness| witness.is_jobs)
+        .map(|witness| tron_address_to_base58(&witness.address))
+        .collect();
+    store.set(0, ACTIVE_WITNESSES_KEY, &active.join(","));
+}
+
+#[substreams::handlers::map]
+fn map_proposal_transitions(
+    clock: Clock,
+    events: ProposalEvents,
+    block_times: Deltas<DeltaInt64>,
+    counts: StoreGetInt64,
+    creations: StoreGetProto<protocol::Proposal>,
+    log: StoreGetArray<String>,
+    active_witnesses: StoreGetString,
+) -> Result<ProposalTransitions, substreams::errors::Error> {
+    let mut transitions = Vec::new();
+
+    for (ordinal, event) in events.events.into_iter().enumerate() {
+        let key = proposal_key(event.proposal_id);
+        let Some(mut proposal) = creations.get_last(&key) else {
+            continue;
+        };
+        apply_log(
+            &mut proposal,
+            &log.get_at(ordinal as u64, &key).unwrap_or_default(),
+        );
+
+        let (kind, witness) = match event.event {
+            Some(Event::Create(_)) => (Kind::Created, String::new()),
+            Some(Event::Approve(Approve { approve: true })) => (Kind::ApprovalAdded, event.owner),
+            Some(Event::Approve(Approve { approve: false })) => {
+                (Kind::ApprovalRemoved, event.owner)
+            }
+            Some(Event::Delete(_)) => (Kind::Canceled, String::new()),
+            None => continue,
+        };
+
+        transitions.push(ProposalTransition {
+            txid: event.txid,
+            kind: kind as i32,
+            witness,
+            parameters: proposal_parameters(&proposal.parameters),
+            proposal: Some(proposal),
+        });
+    }
+
+    // Expired proposals are approved or disapproved at the maintenance, by the super
+    // representatives elected at the previous one. The outcome is an estimate, as the election
+    // relies on vote tallies that can drift from the chain's
+    let maintenance = block_times
+        .deltas
+        .last()
+        .filter(|delta| maintenance_boundary(delta.old_value, delta.new_value).is_some());
+    let active: Vec<String> = active_witnesses
+        .get_first(ACTIVE_WITNESSES_KEY)
+        .map(|active| active.split(',').map(str::to_string).collect())
+        .unwrap_or_default();
+
+    if let (Some(delta), false) = (maintenance, active.is_empty()) {
+        let count = counts.get_last(PROPOSAL_COUNT_KEY).unwrap_or_default();
+
+        for proposal_id in 1..=count {
+            let key = proposal_key(proposal_id);
+            let Some(mut proposal) = creations.get_last(&key) else {
+                continue;
+            };
+            apply_log(&mut proposal, &log.get_last(&key).unwrap_or_default());
+
+            let expiration_time = proposal.expiration_time / 1000;
+            if proposal.state != State::Pending as i32
+                || expiration_time <= delta.old_value
+                || expiration_time > delta.new_value
+            {
+                continue;
+            }
+
+            let approvals = proposal
+                .approvals
+                .iter()
+                .filter(|approval| active.contains(&tron_address_to_base58(approval)))
+                .count();
+            let kind = if approvals >= active.len() * 7 / 10 {
+                proposal.state = State::Approved as i32;
+                Kind::Approved
+            } else {
+                proposal.state = State::Disapproved as i32;
+                Kind::Disapproved
+            };
+
+            transitions.push(ProposalTransition {
+                txid: String::new(),
+                kind: kind as i32,
+                witness: String::new(),
+                parameters: proposal_parameters(&proposal.parameters),
+                proposal: Some(proposal),
+            });
+        }
+    }
+
+    Ok(ProposalTransitions {
+        transitions,
+        clock: Some(clock),
+    })
+}
+
+/// Keeps every proposal in its latest state, mirroring `protocol.Proposal`.
+#[substreams::handlers::store]
+fn store_proposals(transitions: ProposalTransitions, store: StoreSetProto<protocol::Proposal>) {
+    for (ordinal, transition) in transitions.transitions.into_iter().enumerate() {
+        let Some(proposal) = transition.proposal else {
+            continue;
+        };
+        store.set(
+            ordinal as u64,
+            proposal_key(proposal.proposal_id),
+            &proposal,
+        );
+    }
+}
+
+/// Returns the parameters of a proposal sorted by id, named after the chain parameter catalogue.
+fn proposal_parameters(parameters: &HashMap<i64, i64>) -> Vec<ProposalParameter> {
+    let mut parameters: Vec<ProposalParameter> = parameters
+        .iter()
+        .map(|(&id, &value)| {
+            let known = chain_parameter(id);
+            ProposalParameter {
+                id,
+                name: known.map(|p| p.name.to_string()).unwrap_or_default(),
+                unit: known
+                    .map(|p| p.unit.as_str().to_string())
+                    .unwrap_or_default(),
+                value,
+            }
+        })
+        .collect();
+    parameters.sort_by_key(|parameter| parameter.id);
+    parameters
+}
+
+fn proposal_key(proposal_id: i64) -> String {
+    format!("proposal:{}", proposal_id)
+}
+
+/// Returns the expiration time of a proposal created at `now`, in seconds. Proposals expire at the
+/// first maintenance after their minimum lifetime.
+fn expiration_time(now: i64) -> i64 {
+    let next_maintenance =
+        now - now.rem_euclid(MAINTENANCE_INTERVAL_SECONDS) + MAINTENANCE_INTERVAL_SECONDS;
+    let round = (now + PROPOSAL_EXPIRE_SECONDS - next_maintenance) / MAINTENANCE_INTERVAL_SECONDS;
+    next_maintenance + (round + 1) * MAINTENANCE_INTERVAL_SECONDS
+}
+
+/// Applies the `store_proposal_log` entries of a proposal to its created state.
+fn apply_log(proposal: &mut protocol::Proposal, entries: &[String]) {
+    for entry in entries {
+        match entry.split_once(':') {
+            Some(("approve", witness)) => {
+                let Some(address) = base58_to_tron_address(witness) else {
+                    continue;
+                };
+                if !proposal.approvals.contains(&address) {
+                    proposal.approvals.push(address);
+                }
+            }
+            Some(("disapprove", witness)) => {
+                let address = base58_to_tron_address(witness);
+                proposal
+                    .approvals
+                    .retain(|approval| Some(approval) != address.as_ref());
+            }
+            _ if entry == "delete" => proposal.state = State::Canceled as i32,
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expiration_time_on_maintenance() {
+        // 2024-01-01T06:00:00Z
+        let boundary = 1_704_088_800;
+        let hour = 60 * 60;
+
+        assert_eq!(expiration_time(boundary + hour), boundary + 78 * hour);
+        assert_eq!(expiration_time(boundary), boundary + 78 * hour);
+        assert_eq!(expiration_time(boundary - 1), boundary + 72 * hour);
+    }
+
+    #[test]
+    fn apply_log_entries() {
+        let witness = "THxNDMy3y9NP7Bfat9CTKmZ4PFfj1v4gWa";
+        let address = base58_to_tron_address(witness).unwrap();
+        let mut proposal = protocol::Proposal::default();
+
+        apply_log(
+            &mut proposal,
+            &[
+                format!("approve:{}", witness),
+                format!("approve:{}", witness),
+            ],
+        );
+        assert_eq!(proposal.approvals, vec![address]);
+
+        apply_log(
+            &mut proposal,
+            &[format!("disapprove:{}", witness), "delete".to_string()],
+        );
+        assert!(proposal.approvals.is_empty());
+        assert_eq!(proposal.state, State::Canceled as i32);
+    }
+}
@@ -1,7 +1,11 @@
+use crate::pb::protocol;
 use crate::pb::protocol::transaction::contract::ContractType;
 use crate::pb::sf::substreams::tron::v1::Transactions;
 use crate::pb::sf::tron::r#type::v1::Transaction;
-use crate::utils::{extract_from_address, extract_to_address, tron_address_to_base58};
+use crate::utils::{
+    decode_param, evm_address_to_base58, extract_from_address, extract_to_address,
+    tron_address_to_base58,
+};
 use std::collections::HashSet;
 use substreams::pb::sf::substreams::index::v1::Keys;
 
@@ -43,6 +47,22 @@ pub fn transaction_keys(transaction: &Transaction) -> Vec<String> {
                 keys.push(format!("to:{}", tron_address));
             }
         }
+
+        if contract.r#type == ContractType::TriggerSmartContract as i32 {
+            if let Some(trigger) = contract
+                .parameter
+                .as_ref()
+                .and_then(decode_param::<protocol::TriggerSmartContract>)
+            {
+                keys.push(format!(
+                    "call_target:{}",
+                    tron_address_to_base58(&trigger.contract_address)
+                ));
+                if let Some(selector) = trigger.data.get(..4) {
+                    keys.push(format!("method:0x{}", hex::encode(selector)));
+                }
+            }
+        }
     }
 
     if let Some(ref info) = transaction.info {
@@ -50,7 +70,75 @@ pub fn transaction_keys(transaction: &Transaction) -> Vec<String> {
             let tron_address = tron_address_to_base58(&info.contract_address);
             keys.push(format!("contract_address:{}", tron_address));
         }
+
+        for log in &info.log {
+            keys.push(format!(
+                "log_address:{}",
+                evm_address_to_base58(&log.address)
+            ));
+            if let Some(topic0) = log.topics.first() {
+                keys.push(format!("log_topic0:{}", hex::encode(topic0)));
+            }
+        }
     }
 
     keys
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pb::protocol::{transaction_info::Log, TransactionInfo};
+    use crate::trc20::TRANSFER_TOPIC;
+    use prost::Message;
+    use prost_types::Any;
+
+    #[test]
+    fn log_keys() {
+        let usdt = hex::decode("a614f803b6fd780986a42c78ec9c7f77e6ded13c").unwrap();
+        let transaction = Transaction {
+            info: Some(TransactionInfo {
+                log: vec![Log {
+                    address: usdt,
+                    topics: vec![TRANSFER_TOPIC.to_vec()],
+                    data: vec![],
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let keys = transaction_keys(&transaction);
+        assert!(keys.contains(&"log_address:TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t".to_string()));
+        assert!(keys.contains(
+            &"log_topic0:ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+                .to_string()
+        ));
+    }
+
+    #[test]
+    fn trigger_smart_contract_keys() {
+        let usdt = hex::decode("41a614f803b6fd780986a42c78ec9c7f77e6ded13c").unwrap();
+        let trigger = protocol::TriggerSmartContract {
+            owner_address: vec![0x41; 21],
+            contract_address: usdt,
+            data: hex::decode("a9059cbb0000").unwrap(),
+            ..Default::default()
+        };
+        let transaction = Transaction {
+            contracts: vec![protocol::transaction::Contract {
+                r#type: ContractType::TriggerSmartContract as i32,
+                parameter: Some(Any {
+                    type_url: "type.googleapis.com/protocol.TriggerSmartContract".to_string(),
+                    value: trigger.encode_to_vec(),
+                }),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let keys = transaction_keys(&transaction);
+        assert!(keys.contains(&"call_target:TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t".to_string()));
+        assert!(keys.contains(&"method:0xa9059cbb".to_string()));
+    }
+}
@@ -0,0 +1,54 @@
+use crate::pb::sf::substreams::tron::v1::{
+    CallValue, InternalTransaction, InternalTransactions, Transactions,
+};
+use crate::pb::sf::substreams::v1::Clock;
+use crate::pb::sf::tron::r#type::v1::Transaction;
+use crate::utils::evm_address_to_base58;
+
+#[substreams::handlers::map]
+fn map_internal_transactions(
+    transactions: Transactions,
+) -> Result<InternalTransactions, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+
+    Ok(InternalTransactions {
+        internal_transactions: transactions
+            .transactions
+            .iter()
+            .flat_map(|transaction| internal_transactions(transaction, &clock))
+            .collect(),
+        clock: Some(clock),
+    })
+}
+
+/// Returns the flattened internal transactions of a transaction.
+pub fn internal_transactions(transaction: &Transaction, clock: &Clock) -> Vec<InternalTransaction> {
+    let Some(ref info) = transaction.info else {
+        return Vec::new();
+    };
+
+    info.internal_transactions
+        .iter()
+        .enumerate()
+        .map(|(index, internal)| InternalTransaction {
+            parent_txid: hex::encode(&transaction.txid),
+            block_number: clock.number,
+            block_time: clock.timestamp,
+            index: index as u32,
+            hash: hex::encode(&internal.hash),
+            caller: evm_address_to_base58(&internal.caller_address),
+            transfer_to: evm_address_to_base58(&internal.transfer_to_address),
+            call_values: internal
+                .call_value_info
+                .iter()
+                .map(|info| CallValue {
+                    token_id: info.token_id.clone(),
+                    amount: info.call_value,
+                })
+                .collect(),
+            note: String::from_utf8_lossy(&internal.note).into_owned(),
+            rejected: internal.rejected,
+            extra: internal.extra.clone(),
+        })
+        .collect()
+}
@@ -0,0 +1,528 @@
+use crate::assets::asset_key;
+use crate::exchanges::exchange_key;
+use crate::internal::internal_transactions;
+use crate::pb::protocol;
+use crate::pb::protocol::transaction::contract::ContractType;
+use crate::pb::sf::substreams::tron::v1::value_movement::{AssetType, Cause};
+use crate::pb::sf::substreams::tron::v1::{
+    Transactions, Trc10Asset, ValueMovement, ValueMovements,
+};
+use crate::pb::sf::substreams::v1::Clock;
+use crate::pb::sf::tron::r#type::v1::Transaction;
+use crate::transfers::{trc10_transfers, trx_transfers};
+use crate::trc20::trc20_transfers;
+use crate::utils::{
+    contract_execution_failed, decoded_contracts, extract_from_address, tron_address_to_base58,
+};
+use substreams::store::{StoreGet, StoreGetProto};
+
+/// Asset name used for native TRX movements.
+const TRX: &str = "TRX";
+/// Token id used by the Bancor exchanges to designate TRX.
+const EXCHANGE_TRX_TOKEN_ID: &[u8] = b"_";
+
+#[substreams::handlers::map]
+fn map_value_movements(
+    transactions: Transactions,
+    exchanges: StoreGetProto<protocol::Exchange>,
+    assets: StoreGetProto<Trc10Asset>,
+) -> Result<ValueMovements, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+    let exchange = |id| exchanges.get_last(exchange_key(id));
+    let asset = |id: &str| assets.get_last(asset_key(id));
+
+    Ok(ValueMovements {
+        movements: transactions
+            .transactions
+            .iter()
+            .flat_map(|transaction| value_movements(transaction, &clock, &exchange, &asset))
+            .collect(),
+        clock: Some(clock),
+    })
+}
+
+/// Returns every movement of value caused by a transaction: transfers, call values,
+/// internal transactions, protocol payouts, exchange operations, TRC10 issuance purchases and
+/// finally the fee. `exchange` and `asset` resolve an exchange and a TRC10 asset by id.
+pub fn value_movements(
+    transaction: &Transaction,
+    clock: &Clock,
+    exchange: &impl Fn(i64) -> Option<protocol::Exchange>,
+    asset: &impl Fn(&str) -> Option<Trc10Asset>,
+) -> Vec<ValueMovement> {
+    let mut ledger = Ledger {
+        txid: hex::encode(&transaction.txid),
+        clock,
+        movements: Vec::new(),
+    };
+
+    for transfer in trx_transfers(transaction, clock) {
+        ledger.push(
+            AssetType::Trx,
+            TRX,
+            transfer.from,
+            transfer.to,
+            transfer.amount,
+            Cause::TrxTransfer,
+        );
+    }
+
+    for transfer in trc10_transfers(transaction, clock) {
+        ledger.push(
+            AssetType::Trc10,
+            &transfer.asset_id,
+            transfer.from,
+            transfer.to,
+            transfer.amount,
+            Cause::Trc10Transfer,
+        );
+    }
+
+    // The call value of a failed call is refunded
+    for contract in decoded_contracts::<protocol::TriggerSmartContract>(
+        transaction,
+        ContractType::TriggerSmartContract,
+    ) {
+        if contract.call_value == 0 || contract_execution_failed(transaction) {
+            continue;
+        }
+        ledger.push(
+            AssetType::Trx,
+            TRX,
+            tron_address_to_base58(&contract.owner_address),
+            tron_address_to_base58(&contract.contract_address),
+            contract.call_value,
+            Cause::CallValue,
+        );
+    }
+
+    for contract in decoded_contracts::<protocol::ParticipateAssetIssueContract>(
+        transaction,
+        ContractType::ParticipateAssetIssueContract,
+    ) {
+        let buyer = tron_address_to_base58(&contract.owner_address);
+        let issuer = tron_address_to_base58(&contract.to_address);
+        ledger.push(
+            AssetType::Trx,
+            TRX,
+            buyer.clone(),
+            issuer.clone(),
+            contract.amount,
+            Cause::ParticipateAssetIssue,
+        );
+        // Assets issued before ids were introduced are named instead and cannot be resolved
+        let asset_id = String::from_utf8_lossy(&contract.asset_name).into_owned();
+        if let Some(quantity) = asset(&asset_id).and_then(|asset| {
+            let quantity = i128::from(contract.amount) * i128::from(asset.num);
+            quantity.checked_div(i128::from(asset.trx_num))
+        }) {
+            ledger.push(
+                AssetType::Trc10,
+                &asset_id,
+                issuer,
+                buyer,
+                quantity,
+                Cause::ParticipateAssetIssue,
+            );
+        }
+    }
+
+    for transfer in trc20_transfers(transaction, clock) {
+        ledger.push(
+            AssetType::Trc20,
+            &transfer.token_address,
+            transfer.from,
+            transfer.to,
+            transfer.amount,
+            Cause::Trc20Transfer,
+        );
+    }
+
+    for internal in internal_transactions(transaction, clock) {
+        if internal.rejected {
+            continue;
+        }
+        for call_value in internal.call_values {
+            if call_value.amount == 0 {
+                continue;
+            }
+            let (asset_type, asset) = if call_value.token_id.is_empty() {
+                (AssetType::Trx, TRX.to_string())
+            } else {
+                (AssetType::Trc10, call_value.token_id)
+            };
+            ledger.push(
+                asset_type,
+                &asset,
+                internal.caller.clone(),
+                internal.transfer_to.clone(),
+                call_value.amount,
+                Cause::InternalCallValue,
+            );
+        }
+    }
+
+    let Some(ref info) = transaction.info else {
+        return ledger.movements;
+    };
+    let owner = transaction
+        .contracts
+        .iter()
+        .find_map(|contract| {
+            let parameter = contract.parameter.as_ref()?;
+            extract_from_address(contract.r#type, parameter)
+        })
+        .map(|address| tron_address_to_base58(&address))
+        .unwrap_or_default();
+
+    let payouts = [
+        (info.withdraw_amount, Cause::WithdrawReward),
+        (info.unfreeze_amount, Cause::Unfreeze),
+        (info.withdraw_expire_amount, Cause::WithdrawExpireUnfreeze),
+    ];
+    for (amount, cause) in payouts {
+        if amount != 0 {
+            ledger.push(
+                AssetType::Trx,
+                TRX,
+                String::new(),
+                owner.clone(),
+                amount,
+                cause,
+            );
+        }
+    }
+
+    push_exchange_movements(&mut ledger, transaction, info, &owner, exchange);
+
+    if info.fee != 0 {
+        ledger.push(
+            AssetType::Trx,
+            TRX,
+            owner,
+            String::new(),
+            info.fee,
+            Cause::Fee,
+        );
+    }
+
+    ledger.movements
+}
+
+/// Pushes the deposits into and payouts from the Bancor exchanges. The amount of the
+/// counterpart token is reported by `TransactionInfo`, the token itself is the other token of
+/// the exchange's pair.
+fn push_exchange_movements(
+    ledger: &mut Ledger,
+    transaction: &Transaction,
+    info: &protocol::TransactionInfo,
+    owner: &str,
+    exchange: &impl Fn(i64) -> Option<protocol::Exchange>,
+) {
+    let counterpart = |exchange_id, token_id: &[u8]| {
+        exchange(exchange_id).map(|exchange| {
+            if exchange.first_token_id == token_id {
+                exchange.second_token_id
+            } else {
+                exchange.first_token_id
+            }
+        })
+    };
+
+    for contract in decoded_contracts::<protocol::ExchangeCreateContract>(
+        transaction,
+        ContractType::ExchangeCreateContract,
+    ) {
+        ledger.push_exchange(
+            &contract.first_token_id,
+            owner,
+            "",
+            contract.first_token_balance,
+        );
+        ledger.push_exchange(
+            &contract.second_token_id,
+            owner,
+            "",
+            contract.second_token_balance,
+        );
+    }
+
+    for contract in decoded_contracts::<protocol::ExchangeInjectContract>(
+        transaction,
+        ContractType::ExchangeInjectContract,
+    ) {
+        ledger.push_exchange(&contract.token_id, owner, "", contract.quant);
+        if let Some(another) = counterpart(contract.exchange_id, &contract.token_id) {
+            ledger.push_exchange(&another, owner, "", info.exchange_inject_another_amount);
+        }
+    }
+
+    for contract in decoded_contracts::<protocol::ExchangeWithdrawContract>(
+        transaction,
+        ContractType::ExchangeWithdrawContract,
+    ) {
+        ledger.push_exchange(&contract.token_id, "", owner, contract.quant);
+        if let Some(another) = counterpart(contract.exchange_id, &contract.token_id) {
+            ledger.push_exchange(&another, "", owner, info.exchange_withdraw_another_amount);
+        }
+    }
+
+    for contract in decoded_contracts::<protocol::ExchangeTransactionContract>(
+        transaction,
+        ContractType::ExchangeTransactionContract,
+    ) {
+        ledger.push_exchange(&contract.token_id, owner, "", contract.quant);
+        if let Some(received) = counterpart(contract.exchange_id, &contract.token_id) {
+            ledger.push_exchange(&received, "", owner, info.exchange_received_amount);
+        }
+    }
+}
+
+struct Ledger<'a> {
+    txid: String,
+    clock: &'a Clock,
+    movements: Vec<ValueMovement>,
+}
+
+impl Ledger<'_> {
+    fn push(
+        &mut self,
+        asset_type: AssetType,
+        asset: &str,
+        from: String,
+        to: String,
+        amount: impl ToString,
+        cause: Cause,
+    ) {
+        self.movements.push(ValueMovement {
+            txid: self.txid.clone(),
+            block_number: self.clock.number,
+            block_time: self.clock.timestamp,
+            asset_type: asset_type as i32,
+            asset: asset.to_string(),
+            from,
+            to,
+            amount: amount.to_string(),
+            cause: cause as i32,
+        });
+    }
+
+    /// Pushes an exchange movement of `token_id`, `_` standing for TRX.
+    fn push_exchange(&mut self, token_id: &[u8], from: &str, to: &str, amount: i64) {
+        if amount == 0 {
+            return;
+        }
+        let (asset_type, asset) = match token_id {
+            EXCHANGE_TRX_TOKEN_ID => (AssetType::Trx, TRX.to_string()),
+            _ => (
+                AssetType::Trc10,
+                String::from_utf8_lossy(token_id).into_owned(),
+            ),
+        };
+        self.push(
+            asset_type,
+            &asset,
+            from.to_string(),
+            to.to_string(),
+            amount,
+            Cause::Exchange,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+    use prost_types::Any;
+
+    fn contract<T: Message>(
+        contract_type: ContractType,
+        contract: &T,
+    ) -> protocol::transaction::Contract {
+        protocol::transaction::Contract {
+            r#type: contract_type as i32,
+            parameter: Some(Any {
+                type_url: format!(
+                    "type.googleapis.com/protocol.{}",
+                    contract_type.as_str_name()
+                ),
+                value: contract.encode_to_vec(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn transfer_and_fee_movements() {
+        let owner = vec![0x41; 21];
+        let to = vec![0x42; 21];
+        let transaction = Transaction {
+            txid: vec![0xab],
+            contracts: vec![contract(
+                ContractType::TransferContract,
+                &protocol::TransferContract {
+                    owner_address: owner.clone(),
+                    to_address: to.clone(),
+                    amount: 1_000_000,
+                },
+            )],
+            info: Some(protocol::TransactionInfo {
+                fee: 1_100_000,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let movements = value_movements(&transaction, &Clock::default(), &|_| None, &|_| None);
+        let rows: Vec<_> = movements
+            .iter()
+            .map(|m| (m.from.as_str(), m.to.as_str(), m.amount.as_str(), m.cause))
+            .collect();
+        let owner = tron_address_to_base58(&owner);
+        let to = tron_address_to_base58(&to);
+        assert_eq!(
+            rows,
+            vec![
+                (
+                    owner.as_str(),
+                    to.as_str(),
+                    "1000000",
+                    Cause::TrxTransfer as i32
+                ),
+                (owner.as_str(), "", "1100000", Cause::Fee as i32),
+            ]
+        );
+        assert!(movements.iter().all(|m| m.asset == TRX && m.txid == "ab"));
+    }
+
+    #[test]
+    fn exchange_transaction_movements() {
+        let owner = vec![0x41; 21];
+        let transaction = Transaction {
+            contracts: vec![contract(
+                ContractType::ExchangeTransactionContract,
+                &protocol::ExchangeTransactionContract {
+                    owner_address: owner.clone(),
+                    exchange_id: 1,
+                    token_id: EXCHANGE_TRX_TOKEN_ID.to_vec(),
+                    quant: 500,
+                    expected: 1,
+                },
+            )],
+            info: Some(protocol::TransactionInfo {
+                exchange_id: 1,
+                exchange_received_amount: 42,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let exchange = |exchange_id| {
+            Some(protocol::Exchange {
+                exchange_id,
+                first_token_id: b"1002000".to_vec(),
+                second_token_id: EXCHANGE_TRX_TOKEN_ID.to_vec(),
+                ..Default::default()
+            })
+        };
+        let movements = value_movements(&transaction, &Clock::default(), &exchange, &|_| None);
+        let owner = tron_address_to_base58(&owner);
+        assert_eq!(movements.len(), 2);
+        assert_eq!(movements[0].asset, TRX);
+        assert_eq!(movements[0].from, owner);
+        assert_eq!(movements[0].amount, "500");
+        assert_eq!(movements[1].asset_type, AssetType::Trc10 as i32);
+        assert_eq!(movements[1].asset, "1002000");
+        assert_eq!(movements[1].to, owner);
+        assert_eq!(movements[1].amount, "42");
+    }
+
+    #[test]
+    fn participate_asset_issue_movements() {
+        let buyer = vec![0x41; 21];
+        let issuer = vec![0x42; 21];
+        let transaction = Transaction {
+            contracts: vec![contract(
+                ContractType::ParticipateAssetIssueContract,
+                &protocol::ParticipateAssetIssueContract {
+                    owner_address: buyer.clone(),
+                    to_address: issuer.clone(),
+                    asset_name: b"1002000".to_vec(),
+                    amount: 1_000_000,
+                },
+            )],
+            ..Default::default()
+        };
+
+        let asset = |id: &str| {
+            Some(Trc10Asset {
+                id: id.to_string(),
+                trx_num: 3,
+                num: 2,
+                ..Default::default()
+            })
+        };
+        let movements = value_movements(&transaction, &Clock::default(), &|_| None, &asset);
+        let rows: Vec<_> = movements
+            .iter()
+            .map(|m| {
+                (
+                    m.asset.as_str(),
+                    m.from.as_str(),
+                    m.to.as_str(),
+                    m.amount.as_str(),
+                )
+            })
+            .collect();
+        let buyer = tron_address_to_base58(&buyer);
+        let issuer = tron_address_to_base58(&issuer);
+        assert_eq!(
+            rows,
+            vec![
+                (TRX, buyer.as_str(), issuer.as_str(), "1000000"),
+                ("1002000", issuer.as_str(), buyer.as_str(), "666666"),
+            ]
+        );
+        assert!(movements
+            .iter()
+            .all(|m| m.cause == Cause::ParticipateAssetIssue as i32));
+
+        // The asset received cannot be known without the issuance rate
+        let movements = value_movements(&transaction, &Clock::default(), &|_| None, &|_| None);
+        assert_eq!(movements.len(), 1);
+    }
+
+    #[test]
+    fn reverted_call_only_pays_fee() {
+        let owner = vec![0x41; 21];
+        let transaction = Transaction {
+            contracts: vec![contract(
+                ContractType::TriggerSmartContract,
+                &protocol::TriggerSmartContract {
+                    owner_address: owner.clone(),
+                    contract_address: vec![0x42; 21],
+                    call_value: 1_000_000,
+                    call_token_value: 500,
+                    token_id: 1_002_000,
+                    ..Default::default()
+                },
+            )],
+            info: Some(protocol::TransactionInfo {
+                fee: 2_000_000,
+                receipt: Some(protocol::ResourceReceipt {
+                    result: protocol::transaction::result::ContractResult::Revert as i32,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let movements = value_movements(&transaction, &Clock::default(), &|_| None, &|_| None);
+        assert_eq!(movements.len(), 1);
+        assert_eq!(movements[0].cause, Cause::Fee as i32);
+        assert_eq!(movements[0].from, tron_address_to_base58(&owner));
+        assert_eq!(movements[0].amount, "2000000");
+    }
+}
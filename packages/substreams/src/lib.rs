@@ -1,5 +1,21 @@
 
+mod assets;
+mod chain_parameters;
+mod contracts;
+mod events;
+mod exchanges;
+mod governance;
 mod index;
+mod internal;
+mod ledger;
+mod market;
+#[allow(dead_code, clippy::enum_variant_names)]
 mod pb;
+mod permissions;
+mod resources;
+mod staking;
 mod transactions;
+mod trc20;
+mod transfers;
 mod utils;
+mod witnesses;
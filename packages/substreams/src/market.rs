@@ -0,0 +1,169 @@
+use crate::pb::protocol;
+use crate::pb::protocol::transaction::contract::ContractType;
+use crate::pb::sf::substreams::tron::v1::market_event::{Cancelled, Event, Filled, Placed};
+use crate::pb::sf::substreams::tron::v1::{MarketEvent, MarketEvents, Transactions};
+use crate::pb::sf::substreams::v1::Clock;
+use crate::pb::sf::tron::r#type::v1::Transaction;
+use crate::utils::{decoded_contracts, tron_address_to_base58};
+
+#[substreams::handlers::map]
+fn map_market_events(
+    transactions: Transactions,
+) -> Result<MarketEvents, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+
+    Ok(MarketEvents {
+        events: transactions
+            .transactions
+            .iter()
+            .flat_map(|transaction| market_events(transaction, &clock))
+            .collect(),
+        clock: Some(clock),
+    })
+}
+
+/// Returns the market orders placed, filled and cancelled by a transaction. Orders placed are
+/// joined with the order id and the fills reported in the transaction info.
+fn market_events(transaction: &Transaction, clock: &Clock) -> Vec<MarketEvent> {
+    let mut events = Vec::new();
+
+    let txid = hex::encode(&transaction.txid);
+    let info = transaction.info.as_ref();
+    let mut push = |owner: &str, order_id: &[u8], event: Event| {
+        events.push(MarketEvent {
+            txid: txid.clone(),
+            block_number: clock.number,
+            block_time: clock.timestamp,
+            owner: owner.to_string(),
+            order_id: hex::encode(order_id),
+            event: Some(event),
+        })
+    };
+
+    for contract in decoded_contracts::<protocol::MarketSellAssetContract>(
+        transaction,
+        ContractType::MarketSellAssetContract,
+    ) {
+        // The order id is only known once executed, and reported in the transaction info
+        let Some(info) = info else {
+            continue;
+        };
+        let owner = tron_address_to_base58(&contract.owner_address);
+
+        push(
+            &owner,
+            &info.order_id,
+            Event::Placed(Placed {
+                sell_token_id: String::from_utf8_lossy(&contract.sell_token_id).into_owned(),
+                sell_token_quantity: contract.sell_token_quantity,
+                buy_token_id: String::from_utf8_lossy(&contract.buy_token_id).into_owned(),
+                buy_token_quantity: contract.buy_token_quantity,
+            }),
+        );
+
+        // The new order is matched right away against the resting orders
+        for detail in &info.order_details {
+            push(
+                &owner,
+                &detail.taker_order_id,
+                Event::Filled(Filled {
+                    maker_order_id: hex::encode(&detail.maker_order_id),
+                    fill_sell_quantity: detail.fill_sell_quantity,
+                    fill_buy_quantity: detail.fill_buy_quantity,
+                }),
+            );
+        }
+    }
+
+    for contract in decoded_contracts::<protocol::MarketCancelOrderContract>(
+        transaction,
+        ContractType::MarketCancelOrderContract,
+    ) {
+        push(
+            &tron_address_to_base58(&contract.owner_address),
+            &contract.order_id,
+            Event::Cancelled(Cancelled {}),
+        );
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+    use prost_types::Any;
+
+    fn sell_transaction(info: Option<protocol::TransactionInfo>) -> Transaction {
+        let contract = protocol::MarketSellAssetContract {
+            owner_address: vec![0x41; 21],
+            sell_token_id: b"_".to_vec(),
+            sell_token_quantity: 100,
+            buy_token_id: b"1002000".to_vec(),
+            buy_token_quantity: 50,
+        };
+        Transaction {
+            contracts: vec![protocol::transaction::Contract {
+                r#type: ContractType::MarketSellAssetContract as i32,
+                parameter: Some(Any {
+                    type_url: "type.googleapis.com/protocol.MarketSellAssetContract".to_string(),
+                    value: contract.encode_to_vec(),
+                }),
+                ..Default::default()
+            }],
+            info,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn placed_order_joined_with_fills() {
+        let detail = |maker: u8, sell: i64, buy: i64| protocol::MarketOrderDetail {
+            maker_order_id: vec![maker],
+            // Distinct from the order id, to tell which one keys the fills
+            taker_order_id: vec![0xbb],
+            fill_sell_quantity: sell,
+            fill_buy_quantity: buy,
+        };
+        let transaction = sell_transaction(Some(protocol::TransactionInfo {
+            order_id: vec![0xaa],
+            order_details: vec![detail(0x01, 60, 30), detail(0x02, 40, 20)],
+            ..Default::default()
+        }));
+
+        let events = market_events(&transaction, &Clock::default());
+        let owner = tron_address_to_base58(&[0x41; 21]);
+        assert!(events.iter().all(|event| event.owner == owner));
+        let order_ids: Vec<_> = events.iter().map(|event| event.order_id.as_str()).collect();
+        assert_eq!(order_ids, ["aa", "bb", "bb"]);
+
+        let events: Vec<_> = events.into_iter().filter_map(|event| event.event).collect();
+        assert_eq!(
+            events,
+            [
+                Event::Placed(Placed {
+                    sell_token_id: "_".to_string(),
+                    sell_token_quantity: 100,
+                    buy_token_id: "1002000".to_string(),
+                    buy_token_quantity: 50,
+                }),
+                Event::Filled(Filled {
+                    maker_order_id: "01".to_string(),
+                    fill_sell_quantity: 60,
+                    fill_buy_quantity: 30,
+                }),
+                Event::Filled(Filled {
+                    maker_order_id: "02".to_string(),
+                    fill_sell_quantity: 40,
+                    fill_buy_quantity: 20,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn order_without_info_is_skipped() {
+        assert!(market_events(&sell_transaction(None), &Clock::default()).is_empty());
+    }
+}
@@ -8,4 +8,1376 @@ pub struct Transactions {
     #[prost(message, optional, tag="2")]
     pub clock: ::core::option::Option<super::super::v1::Clock>,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TrxTransfers {
+    #[prost(message, repeated, tag="1")]
+    pub transfers: ::prost::alloc::vec::Vec<TrxTransfer>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// TrxTransfer is a native TRX transfer made through a `TransferContract`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TrxTransfer {
+    /// Transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Base58 encoded sender address.
+    #[prost(string, tag="4")]
+    pub from: ::prost::alloc::string::String,
+    /// Base58 encoded recipient address.
+    #[prost(string, tag="5")]
+    pub to: ::prost::alloc::string::String,
+    /// Amount transferred, in sun.
+    #[prost(int64, tag="6")]
+    pub amount: i64,
+    /// Fee paid by the transaction, in sun.
+    #[prost(int64, tag="7")]
+    pub fee: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trc10Transfers {
+    #[prost(message, repeated, tag="1")]
+    pub transfers: ::prost::alloc::vec::Vec<Trc10Transfer>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// Trc10Transfer is a TRC10 asset transfer, either through a `TransferAssetContract`
+/// or attached as `call_token_value` to a successful `TriggerSmartContract`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trc10Transfer {
+    /// Transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Contract type that moved the asset, e.g. `TransferAssetContract`.
+    #[prost(enumeration="super::super::super::super::protocol::transaction::contract::ContractType", tag="4")]
+    pub contract_type: i32,
+    /// TRC10 asset id. Transfers made before ids were introduced carry the asset name instead.
+    #[prost(string, tag="5")]
+    pub asset_id: ::prost::alloc::string::String,
+    /// Base58 encoded sender address.
+    #[prost(string, tag="6")]
+    pub from: ::prost::alloc::string::String,
+    /// Base58 encoded recipient address.
+    #[prost(string, tag="7")]
+    pub to: ::prost::alloc::string::String,
+    /// Amount transferred, in the asset's smallest unit.
+    #[prost(int64, tag="8")]
+    pub amount: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trc20Transfers {
+    #[prost(message, repeated, tag="1")]
+    pub transfers: ::prost::alloc::vec::Vec<Trc20Transfer>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// Trc20Transfer is a `Transfer(address,address,uint256)` event emitted by a TRC20 token.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trc20Transfer {
+    /// Transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Base58 encoded address of the token contract that emitted the event.
+    #[prost(string, tag="4")]
+    pub token_address: ::prost::alloc::string::String,
+    /// Base58 encoded sender address.
+    #[prost(string, tag="5")]
+    pub from: ::prost::alloc::string::String,
+    /// Base58 encoded recipient address.
+    #[prost(string, tag="6")]
+    pub to: ::prost::alloc::string::String,
+    /// Amount transferred as a decimal string, in the token's smallest unit.
+    #[prost(string, tag="7")]
+    pub amount: ::prost::alloc::string::String,
+    /// Index of the event in the transaction's logs.
+    #[prost(uint32, tag="8")]
+    pub log_index: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trc20Approvals {
+    #[prost(message, repeated, tag="1")]
+    pub approvals: ::prost::alloc::vec::Vec<Trc20Approval>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// Trc20Approval is an `Approval(address,address,uint256)` event emitted by a TRC20 token.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trc20Approval {
+    /// Transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Base58 encoded address of the token contract that emitted the event.
+    #[prost(string, tag="4")]
+    pub token_address: ::prost::alloc::string::String,
+    /// Base58 encoded address of the token owner.
+    #[prost(string, tag="5")]
+    pub owner: ::prost::alloc::string::String,
+    /// Base58 encoded address allowed to spend the owner's tokens.
+    #[prost(string, tag="6")]
+    pub spender: ::prost::alloc::string::String,
+    /// Approved amount as a decimal string, in the token's smallest unit.
+    #[prost(string, tag="7")]
+    pub amount: ::prost::alloc::string::String,
+    /// Index of the event in the transaction's logs.
+    #[prost(uint32, tag="8")]
+    pub log_index: u32,
+    /// True when the approved amount is the maximum uint256 value.
+    #[prost(bool, tag="9")]
+    pub unlimited: bool,
+    /// True when the approved amount is zero.
+    #[prost(bool, tag="10")]
+    pub revoked: bool,
+}
+/// Trc20Allowance is the current allowance of a spender over an owner's tokens,
+/// as set by the latest `Approval` event.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trc20Allowance {
+    #[prost(string, tag="1")]
+    pub token_address: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub spender: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(bool, tag="5")]
+    pub unlimited: bool,
+    /// Transaction id, hex encoded, of the latest approval.
+    #[prost(string, tag="6")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="7")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="8")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InternalTransactions {
+    #[prost(message, repeated, tag="1")]
+    pub internal_transactions: ::prost::alloc::vec::Vec<InternalTransaction>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// InternalTransaction is a flattened `protocol.InternalTransaction`, a call or value
+/// transfer made by a smart contract during the execution of its parent transaction.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InternalTransaction {
+    /// Parent transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub parent_txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Index of the internal transaction in its parent transaction.
+    #[prost(uint32, tag="4")]
+    pub index: u32,
+    /// Internal transaction hash, hex encoded.
+    #[prost(string, tag="5")]
+    pub hash: ::prost::alloc::string::String,
+    /// Base58 encoded address of the calling contract.
+    #[prost(string, tag="6")]
+    pub caller: ::prost::alloc::string::String,
+    /// Base58 encoded address of the callee or value recipient.
+    #[prost(string, tag="7")]
+    pub transfer_to: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="8")]
+    pub call_values: ::prost::alloc::vec::Vec<CallValue>,
+    /// Note, decoded as UTF-8 (e.g. `call`, `create`, `suicide`).
+    #[prost(string, tag="9")]
+    pub note: ::prost::alloc::string::String,
+    #[prost(bool, tag="10")]
+    pub rejected: bool,
+    #[prost(string, tag="11")]
+    pub extra: ::prost::alloc::string::String,
+}
+/// CallValue is a value attached to an internal transaction.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CallValue {
+    /// TRC10 token id, empty when the value is in TRX.
+    #[prost(string, tag="1")]
+    pub token_id: ::prost::alloc::string::String,
+    /// Amount, in sun for TRX or in the asset's smallest unit for TRC10.
+    #[prost(int64, tag="2")]
+    pub amount: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValueMovements {
+    #[prost(message, repeated, tag="1")]
+    pub movements: ::prost::alloc::vec::Vec<ValueMovement>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// ValueMovement is a single movement of value between two accounts. An empty `from`
+/// means the value comes from the protocol (rewards, unstaked balance, exchange pools),
+/// an empty `to` means the value is burned or handed to the protocol.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValueMovement {
+    /// Transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(enumeration="value_movement::AssetType", tag="4")]
+    pub asset_type: i32,
+    /// `TRX`, the TRC10 asset id or the base58 TRC20 token address.
+    #[prost(string, tag="5")]
+    pub asset: ::prost::alloc::string::String,
+    /// Base58 encoded sender address.
+    #[prost(string, tag="6")]
+    pub from: ::prost::alloc::string::String,
+    /// Base58 encoded recipient address.
+    #[prost(string, tag="7")]
+    pub to: ::prost::alloc::string::String,
+    /// Amount moved as a decimal string, in the asset's smallest unit.
+    #[prost(string, tag="8")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(enumeration="value_movement::Cause", tag="9")]
+    pub cause: i32,
+}
+/// Nested message and enum types in `ValueMovement`.
+pub mod value_movement {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum AssetType {
+        Unspecified = 0,
+        Trx = 1,
+        Trc10 = 2,
+        Trc20 = 3,
+    }
+    impl AssetType {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                AssetType::Unspecified => "ASSET_TYPE_UNSPECIFIED",
+                AssetType::Trx => "ASSET_TYPE_TRX",
+                AssetType::Trc10 => "ASSET_TYPE_TRC10",
+                AssetType::Trc20 => "ASSET_TYPE_TRC20",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "ASSET_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+                "ASSET_TYPE_TRX" => Some(Self::Trx),
+                "ASSET_TYPE_TRC10" => Some(Self::Trc10),
+                "ASSET_TYPE_TRC20" => Some(Self::Trc20),
+                _ => None,
+            }
+        }
+    }
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Cause {
+        Unspecified = 0,
+        TrxTransfer = 1,
+        Trc10Transfer = 2,
+        Trc20Transfer = 3,
+        /// TRX sent along a `TriggerSmartContract`.
+        CallValue = 4,
+        InternalCallValue = 5,
+        Fee = 6,
+        WithdrawReward = 7,
+        Unfreeze = 8,
+        WithdrawExpireUnfreeze = 9,
+        Exchange = 10,
+        /// TRX paid to the issuer of a TRC10 asset and the asset received in exchange, through a
+        /// `ParticipateAssetIssueContract`.
+        ParticipateAssetIssue = 11,
+    }
+    impl Cause {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Cause::Unspecified => "CAUSE_UNSPECIFIED",
+                Cause::TrxTransfer => "CAUSE_TRX_TRANSFER",
+                Cause::Trc10Transfer => "CAUSE_TRC10_TRANSFER",
+                Cause::Trc20Transfer => "CAUSE_TRC20_TRANSFER",
+                Cause::CallValue => "CAUSE_CALL_VALUE",
+                Cause::InternalCallValue => "CAUSE_INTERNAL_CALL_VALUE",
+                Cause::Fee => "CAUSE_FEE",
+                Cause::WithdrawReward => "CAUSE_WITHDRAW_REWARD",
+                Cause::Unfreeze => "CAUSE_UNFREEZE",
+                Cause::WithdrawExpireUnfreeze => "CAUSE_WITHDRAW_EXPIRE_UNFREEZE",
+                Cause::Exchange => "CAUSE_EXCHANGE",
+                Cause::ParticipateAssetIssue => "CAUSE_PARTICIPATE_ASSET_ISSUE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "CAUSE_UNSPECIFIED" => Some(Self::Unspecified),
+                "CAUSE_TRX_TRANSFER" => Some(Self::TrxTransfer),
+                "CAUSE_TRC10_TRANSFER" => Some(Self::Trc10Transfer),
+                "CAUSE_TRC20_TRANSFER" => Some(Self::Trc20Transfer),
+                "CAUSE_CALL_VALUE" => Some(Self::CallValue),
+                "CAUSE_INTERNAL_CALL_VALUE" => Some(Self::InternalCallValue),
+                "CAUSE_FEE" => Some(Self::Fee),
+                "CAUSE_WITHDRAW_REWARD" => Some(Self::WithdrawReward),
+                "CAUSE_UNFREEZE" => Some(Self::Unfreeze),
+                "CAUSE_WITHDRAW_EXPIRE_UNFREEZE" => Some(Self::WithdrawExpireUnfreeze),
+                "CAUSE_EXCHANGE" => Some(Self::Exchange),
+                "CAUSE_PARTICIPATE_ASSET_ISSUE" => Some(Self::ParticipateAssetIssue),
+                _ => None,
+            }
+        }
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Events {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<Event>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// Event is a smart contract event log (`protocol.TransactionInfo.Log`).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Event {
+    /// Transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Base58 encoded address of the contract that emitted the event.
+    #[prost(string, tag="4")]
+    pub address: ::prost::alloc::string::String,
+    /// Hex encoded topics, the first one being the event signature hash for non-anonymous events.
+    #[prost(string, repeated, tag="5")]
+    pub topics: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Hex encoded non-indexed event data.
+    #[prost(string, tag="6")]
+    pub data: ::prost::alloc::string::String,
+    /// Index of the event in the transaction's logs.
+    #[prost(uint32, tag="7")]
+    pub log_index: u32,
+    /// Index of the event across all the logs of the block.
+    #[prost(uint64, tag="8")]
+    pub ordinal: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContractDeployments {
+    #[prost(message, repeated, tag="1")]
+    pub deployments: ::prost::alloc::vec::Vec<ContractDeployment>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// ContractDeployment is a smart contract deployed through a successful `CreateSmartContract`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContractDeployment {
+    /// Transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Base58 encoded address of the deployer.
+    #[prost(string, tag="4")]
+    pub deployer: ::prost::alloc::string::String,
+    /// Base58 encoded address of the deployed contract.
+    #[prost(string, tag="5")]
+    pub contract_address: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub name: ::prost::alloc::string::String,
+    /// SHA-256 of the deployment bytecode, hex encoded.
+    #[prost(string, tag="7")]
+    pub bytecode_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="8")]
+    pub abi: ::core::option::Option<super::super::super::super::protocol::smart_contract::Abi>,
+    /// Percentage of the energy paid by the callers, the rest being paid by the deployer.
+    #[prost(int64, tag="9")]
+    pub consume_user_resource_percent: i64,
+    /// Maximum energy the deployer pays per call.
+    #[prost(int64, tag="10")]
+    pub origin_energy_limit: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakeV2Events {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<StakeV2Event>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// StakeV2Event is a Stake 2.0 operation, joined with the amounts realised by the
+/// protocol as reported in `protocol.TransactionInfo`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakeV2Event {
+    /// Transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Base58 encoded address of the staker.
+    #[prost(string, tag="4")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(oneof="stake_v2_event::Event", tags="5, 6, 7, 8")]
+    pub event: ::core::option::Option<stake_v2_event::Event>,
+}
+/// Nested message and enum types in `StakeV2Event`.
+pub mod stake_v2_event {
+    /// Freeze is a `FreezeBalanceV2Contract`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Freeze {
+        #[prost(enumeration="super::super::super::super::super::protocol::ResourceCode", tag="1")]
+        pub resource: i32,
+        /// Amount staked, in sun.
+        #[prost(int64, tag="2")]
+        pub amount: i64,
+    }
+    /// Unfreeze is an `UnfreezeBalanceV2Contract`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Unfreeze {
+        #[prost(enumeration="super::super::super::super::super::protocol::ResourceCode", tag="1")]
+        pub resource: i32,
+        /// Amount unstaked, in sun.
+        #[prost(int64, tag="2")]
+        pub amount: i64,
+        /// Expired unstaked amount automatically withdrawn by the operation, in sun.
+        #[prost(int64, tag="3")]
+        pub withdraw_expire_amount: i64,
+        /// `unfreeze_amount` reported by the transaction info, in sun. java-tron only sets it for
+        /// Stake 1.0 unfreezes and leaves it at 0 here, the unstaked `amount` being withdrawable
+        /// only once the unbonding period has passed.
+        #[prost(int64, tag="4")]
+        pub unfreeze_amount: i64,
+    }
+    /// WithdrawExpireUnfreeze is a `WithdrawExpireUnfreezeContract`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct WithdrawExpireUnfreeze {
+        /// Amount withdrawn, in sun.
+        #[prost(int64, tag="1")]
+        pub amount: i64,
+    }
+    /// CancelAllUnfreeze is a `CancelAllUnfreezeV2Contract`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct CancelAllUnfreeze {
+        /// Amount restaked per resource name (`BANDWIDTH`, `ENERGY`, `TRON_POWER`), in sun.
+        #[prost(map="string, int64", tag="1")]
+        pub cancelled_amounts: ::std::collections::HashMap<::prost::alloc::string::String, i64>,
+        /// Expired unstaked amount withdrawn by the operation, in sun.
+        #[prost(int64, tag="2")]
+        pub withdraw_expire_amount: i64,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="5")]
+        Freeze(Freeze),
+        #[prost(message, tag="6")]
+        Unfreeze(Unfreeze),
+        #[prost(message, tag="7")]
+        WithdrawExpireUnfreeze(WithdrawExpireUnfreeze),
+        #[prost(message, tag="8")]
+        CancelAllUnfreeze(CancelAllUnfreeze),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationEvents {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<DelegationEvent>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// DelegationEvent is a `DelegateResourceContract` or an `UnDelegateResourceContract`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationEvent {
+    /// Transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Base58 encoded address of the account delegating its staked resources.
+    #[prost(string, tag="4")]
+    pub delegator: ::prost::alloc::string::String,
+    /// Base58 encoded address of the account receiving the resources.
+    #[prost(string, tag="5")]
+    pub receiver: ::prost::alloc::string::String,
+    #[prost(enumeration="super::super::super::super::protocol::ResourceCode", tag="6")]
+    pub resource: i32,
+    /// Staked amount backing the delegated resources, in sun.
+    #[prost(int64, tag="7")]
+    pub balance: i64,
+    /// True for an `UnDelegateResourceContract`.
+    #[prost(bool, tag="8")]
+    pub undelegate: bool,
+    #[prost(bool, tag="9")]
+    pub lock: bool,
+    /// Lock period, in blocks.
+    #[prost(int64, tag="10")]
+    pub lock_period: i64,
+}
+/// DelegationLock is the lock of the locked delegations between two accounts, restarted by every
+/// new locked delegation.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DelegationLock {
+    /// Block of the latest locked delegation.
+    #[prost(uint64, tag="1")]
+    pub block_number: u64,
+    /// Block after which the locked balance is unlocked: `block_number` plus the lock period, or
+    /// plus java-tron's default 3 days period when the delegation sets none.
+    #[prost(uint64, tag="2")]
+    pub expire_block_number: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationDeltas {
+    #[prost(message, repeated, tag="1")]
+    pub deltas: ::prost::alloc::vec::Vec<DelegationDelta>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// DelegationDelta is a change of the resources delegated by an account to another.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationDelta {
+    #[prost(string, tag="1")]
+    pub delegator: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub receiver: ::prost::alloc::string::String,
+    #[prost(enumeration="super::super::super::super::protocol::ResourceCode", tag="3")]
+    pub resource: i32,
+    /// Delegated staked amount before and after the block, in sun.
+    #[prost(int64, tag="4")]
+    pub old_balance: i64,
+    #[prost(int64, tag="5")]
+    pub new_balance: i64,
+    /// Lock of the delegated balance, unset for the unlocked one.
+    #[prost(message, optional, tag="6")]
+    pub lock: ::core::option::Option<DelegationLock>,
+    /// Whether this is the locked balance, kept apart from the unlocked one as on chain.
+    #[prost(bool, tag="7")]
+    pub locked: bool,
+}
+/// ResourceUsage is the energy and bandwidth consumed in a block, aggregated from the
+/// `protocol.ResourceReceipt` of every transaction.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResourceUsage {
+    /// Usage per called contract, for `TriggerSmartContract` transactions.
+    #[prost(message, repeated, tag="1")]
+    pub by_contract: ::prost::alloc::vec::Vec<ResourceUsageAggregate>,
+    /// Usage per caller (transaction owner), for every transaction.
+    #[prost(message, repeated, tag="2")]
+    pub by_caller: ::prost::alloc::vec::Vec<ResourceUsageAggregate>,
+    #[prost(message, optional, tag="3")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResourceUsageAggregate {
+    /// Base58 encoded address of the called contract or of the caller.
+    #[prost(string, tag="1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub transaction_count: u64,
+    /// Energy paid by the callers from their staked energy.
+    #[prost(int64, tag="3")]
+    pub energy_usage: i64,
+    /// TRX burned by the callers to pay for energy, in sun.
+    #[prost(int64, tag="4")]
+    pub energy_fee: i64,
+    /// Energy sponsored by the contract owners.
+    #[prost(int64, tag="5")]
+    pub origin_energy_usage: i64,
+    #[prost(int64, tag="6")]
+    pub energy_usage_total: i64,
+    #[prost(int64, tag="7")]
+    pub energy_penalty_total: i64,
+    #[prost(int64, tag="8")]
+    pub net_usage: i64,
+    /// TRX burned to pay for bandwidth, in sun.
+    #[prost(int64, tag="9")]
+    pub net_fee: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakeV1Events {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<StakeV1Event>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// StakeV1Event is a legacy Stake 1.0 operation, joined with the amount realised by the
+/// protocol as reported in `protocol.TransactionInfo`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakeV1Event {
+    /// Transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Base58 encoded address of the staker.
+    #[prost(string, tag="4")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(oneof="stake_v1_event::Event", tags="5, 6")]
+    pub event: ::core::option::Option<stake_v1_event::Event>,
+}
+/// Nested message and enum types in `StakeV1Event`.
+pub mod stake_v1_event {
+    /// Freeze is a `FreezeBalanceContract`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Freeze {
+        #[prost(enumeration="super::super::super::super::super::protocol::ResourceCode", tag="1")]
+        pub resource: i32,
+        /// Amount frozen, in sun.
+        #[prost(int64, tag="2")]
+        pub frozen_balance: i64,
+        /// Minimum freezing duration, in days.
+        #[prost(int64, tag="3")]
+        pub frozen_duration: i64,
+        /// Base58 encoded address of the account the resources are delegated to, empty when frozen for the owner.
+        #[prost(string, tag="4")]
+        pub receiver: ::prost::alloc::string::String,
+    }
+    /// Unfreeze is an `UnfreezeBalanceContract`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Unfreeze {
+        #[prost(enumeration="super::super::super::super::super::protocol::ResourceCode", tag="1")]
+        pub resource: i32,
+        /// Base58 encoded address of the account the resources were delegated to, empty when frozen for the owner.
+        #[prost(string, tag="2")]
+        pub receiver: ::prost::alloc::string::String,
+        /// Amount unfrozen and returned to the owner, in sun.
+        #[prost(int64, tag="3")]
+        pub unfreeze_amount: i64,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="5")]
+        Freeze(Freeze),
+        #[prost(message, tag="6")]
+        Unfreeze(Unfreeze),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteEvents {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<VoteEvent>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// VoteEvent is a `VoteWitnessContract`, which replaces the whole vote allocation of the voter,
+/// or a Stake 1.0 `UnfreezeBalanceContract`, which clears it.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteEvent {
+    /// Transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Base58 encoded address of the voter.
+    #[prost(string, tag="4")]
+    pub voter: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="5")]
+    pub votes: ::prost::alloc::vec::Vec<WitnessVote>,
+    /// Whether the allocation is cleared by an unfreeze, `votes` being empty.
+    #[prost(bool, tag="6")]
+    pub cleared: bool,
+}
+/// WitnessVote is a single entry of a vote allocation.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WitnessVote {
+    /// Base58 encoded address of the witness (super representative candidate).
+    #[prost(string, tag="1")]
+    pub witness: ::prost::alloc::string::String,
+    /// Number of votes, in TRON power units.
+    #[prost(int64, tag="2")]
+    pub count: i64,
+}
+/// VoterVotes is the current vote allocation of a voter, as kept by `store_voter_votes`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoterVotes {
+    /// Base58 encoded address of the voter.
+    #[prost(string, tag="1")]
+    pub voter: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="2")]
+    pub votes: ::prost::alloc::vec::Vec<WitnessVote>,
+    /// Block of the `VoteWitnessContract` that set this allocation, or of the unfreeze that cleared it.
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    /// Transaction id of that transaction, hex encoded.
+    #[prost(string, tag="4")]
+    pub txid: ::prost::alloc::string::String,
+}
+/// WitnessRanking is the witness ranking at a maintenance boundary, emitted on the first block
+/// of every 6 hours maintenance period. It is empty on every other block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WitnessRanking {
+    /// Maintenance boundary crossed by this block.
+    #[prost(message, optional, tag="1")]
+    pub maintenance_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Witnesses ranked by vote count, descending. `is_jobs` flags the elected super representatives.
+    #[prost(message, repeated, tag="2")]
+    pub witnesses: ::prost::alloc::vec::Vec<super::super::super::super::protocol::Witness>,
+    #[prost(message, optional, tag="3")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// BlockProduction is the production of a block by its witness, along with the slots missed
+/// since the previous block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockProduction {
+    /// Base58 encoded address of the witness that produced the block.
+    #[prost(string, tag="1")]
+    pub witness: ::prost::alloc::string::String,
+    /// Absolute slot of the block, i.e. its timestamp divided by the 3 seconds block interval.
+    #[prost(int64, tag="2")]
+    pub slot: i64,
+    #[prost(message, repeated, tag="3")]
+    pub missed_slots: ::prost::alloc::vec::Vec<MissedSlot>,
+    #[prost(message, optional, tag="4")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// MissedSlot is a slot left empty between two blocks.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MissedSlot {
+    /// Absolute slot that was missed.
+    #[prost(int64, tag="1")]
+    pub slot: i64,
+    /// Base58 encoded address of the witness scheduled on the slot, as learned from the previous
+    /// production round. Empty when no block was seen yet at this position of the schedule.
+    #[prost(string, tag="2")]
+    pub witness: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RewardEvents {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<RewardEvent>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// RewardEvent is a voting reward claim or a witness brokerage change.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RewardEvent {
+    /// Transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Base58 encoded address of the claiming account or of the witness.
+    #[prost(string, tag="4")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(oneof="reward_event::Event", tags="5, 6")]
+    pub event: ::core::option::Option<reward_event::Event>,
+}
+/// Nested message and enum types in `RewardEvent`.
+pub mod reward_event {
+    /// Withdraw is a `WithdrawBalanceContract`, claiming the block and voting rewards of the owner.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Withdraw {
+        /// Amount claimed, in sun, as reported by `withdraw_amount` in `protocol.TransactionInfo`.
+        #[prost(int64, tag="1")]
+        pub amount: i64,
+    }
+    /// BrokerageUpdate is an `UpdateBrokerageContract`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct BrokerageUpdate {
+        /// Percentage of the voting rewards kept by the witness, from 0 to 100.
+        #[prost(int32, tag="1")]
+        pub brokerage: i32,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="5")]
+        Withdraw(Withdraw),
+        #[prost(message, tag="6")]
+        BrokerageUpdate(BrokerageUpdate),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalEvents {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<ProposalEvent>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// ProposalEvent is a `ProposalCreateContract`, `ProposalApproveContract` or `ProposalDeleteContract`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalEvent {
+    /// Transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Base58 encoded address of the proposer, or of the approving witness.
+    #[prost(string, tag="4")]
+    pub owner: ::prost::alloc::string::String,
+    /// Id of the proposal. For a creation, it is the id assigned by the chain, i.e. the number of
+    /// proposals created so far.
+    #[prost(int64, tag="5")]
+    pub proposal_id: i64,
+    #[prost(oneof="proposal_event::Event", tags="6, 7, 8")]
+    pub event: ::core::option::Option<proposal_event::Event>,
+}
+/// Nested message and enum types in `ProposalEvent`.
+pub mod proposal_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Create {
+        /// Chain parameters changed by the proposal, sorted by id.
+        #[prost(message, repeated, tag="1")]
+        pub parameters: ::prost::alloc::vec::Vec<super::ProposalParameter>,
+        /// Maintenance at which the approvals are counted.
+        #[prost(message, optional, tag="2")]
+        pub expiration_time: ::core::option::Option<::prost_types::Timestamp>,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Approve {
+        /// False when the witness removes its previous approval.
+        #[prost(bool, tag="1")]
+        pub approve: bool,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Delete {
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="6")]
+        Create(Create),
+        #[prost(message, tag="7")]
+        Approve(Approve),
+        #[prost(message, tag="8")]
+        Delete(Delete),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalTransitions {
+    #[prost(message, repeated, tag="1")]
+    pub transitions: ::prost::alloc::vec::Vec<ProposalTransition>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// ProposalTransition is a change of a proposal, with its whole state after the change.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalTransition {
+    /// Transaction id, hex encoded. Empty for the approval or disapproval of an expired proposal,
+    /// which happens at the maintenance.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(enumeration="proposal_transition::Kind", tag="2")]
+    pub kind: i32,
+    /// Base58 encoded address of the witness, for approval changes.
+    #[prost(string, tag="3")]
+    pub witness: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="4")]
+    pub proposal: ::core::option::Option<super::super::super::super::protocol::Proposal>,
+    /// Chain parameters changed by the proposal, sorted by id.
+    #[prost(message, repeated, tag="5")]
+    pub parameters: ::prost::alloc::vec::Vec<ProposalParameter>,
+}
+/// Nested message and enum types in `ProposalTransition`.
+pub mod proposal_transition {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Kind {
+        Unspecified = 0,
+        Created = 1,
+        ApprovalAdded = 2,
+        ApprovalRemoved = 3,
+        Canceled = 4,
+        /// Outcome of an expired proposal at the maintenance. Estimated from the super representatives
+        /// elected by the vote tallies of `store_witness_votes`, which can drift from the chain's (see
+        /// `map_vote_events`).
+        Approved = 5,
+        Disapproved = 6,
+    }
+    impl Kind {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Kind::Unspecified => "KIND_UNSPECIFIED",
+                Kind::Created => "KIND_CREATED",
+                Kind::ApprovalAdded => "KIND_APPROVAL_ADDED",
+                Kind::ApprovalRemoved => "KIND_APPROVAL_REMOVED",
+                Kind::Canceled => "KIND_CANCELED",
+                Kind::Approved => "KIND_APPROVED",
+                Kind::Disapproved => "KIND_DISAPPROVED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "KIND_UNSPECIFIED" => Some(Self::Unspecified),
+                "KIND_CREATED" => Some(Self::Created),
+                "KIND_APPROVAL_ADDED" => Some(Self::ApprovalAdded),
+                "KIND_APPROVAL_REMOVED" => Some(Self::ApprovalRemoved),
+                "KIND_CANCELED" => Some(Self::Canceled),
+                "KIND_APPROVED" => Some(Self::Approved),
+                "KIND_DISAPPROVED" => Some(Self::Disapproved),
+                _ => None,
+            }
+        }
+    }
+}
+/// ProposalParameter is a chain parameter change carried by a proposal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalParameter {
+    /// Id of the parameter, the key of `ProposalCreateContract.parameters`.
+    #[prost(int64, tag="1")]
+    pub id: i64,
+    /// Name of the parameter, e.g. `getEnergyFee`. Empty for an id unknown to this package.
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+    /// Unit of the value, e.g. `sun` or `flag`. Empty for an id unknown to this package.
+    #[prost(string, tag="3")]
+    pub unit: ::prost::alloc::string::String,
+    #[prost(int64, tag="4")]
+    pub value: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContractSettingChanges {
+    #[prost(message, repeated, tag="1")]
+    pub changes: ::prost::alloc::vec::Vec<ContractSettingChange>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// ContractSettingChange is a change of a deployed smart contract's settings by its owner.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContractSettingChange {
+    /// Transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Base58 encoded address of the affected contract.
+    #[prost(string, tag="4")]
+    pub contract_address: ::prost::alloc::string::String,
+    /// Base58 encoded address of the contract owner who made the change.
+    #[prost(string, tag="5")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(oneof="contract_setting_change::Change", tags="6, 7, 8")]
+    pub change: ::core::option::Option<contract_setting_change::Change>,
+}
+/// Nested message and enum types in `ContractSettingChange`.
+pub mod contract_setting_change {
+    /// UpdateSetting is an `UpdateSettingContract`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct UpdateSetting {
+        /// New percentage of the energy paid by the callers, the rest being paid by the owner.
+        #[prost(int64, tag="1")]
+        pub consume_user_resource_percent: i64,
+    }
+    /// UpdateEnergyLimit is an `UpdateEnergyLimitContract`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct UpdateEnergyLimit {
+        /// New maximum energy the owner pays per call.
+        #[prost(int64, tag="1")]
+        pub origin_energy_limit: i64,
+    }
+    /// ClearAbi is a `ClearAbiContract`, removing the ABI stored on chain.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct ClearAbi {
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Oneof)]
+    pub enum Change {
+        #[prost(message, tag="6")]
+        UpdateSetting(UpdateSetting),
+        #[prost(message, tag="7")]
+        UpdateEnergyLimit(UpdateEnergyLimit),
+        #[prost(message, tag="8")]
+        ClearAbi(ClearAbi),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PermissionUpdates {
+    #[prost(message, repeated, tag="1")]
+    pub updates: ::prost::alloc::vec::Vec<PermissionUpdate>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// PermissionUpdate is an `AccountPermissionUpdateContract`, replacing all the permissions of an account.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PermissionUpdate {
+    /// Transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Base58 encoded address of the account.
+    #[prost(string, tag="4")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="5")]
+    pub owner_permission: ::core::option::Option<AccountPermission>,
+    /// Only set for witness accounts.
+    #[prost(message, optional, tag="6")]
+    pub witness_permission: ::core::option::Option<AccountPermission>,
+    #[prost(message, repeated, tag="7")]
+    pub active_permissions: ::prost::alloc::vec::Vec<AccountPermission>,
+}
+/// AccountPermission is a decoded `protocol.Permission`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountPermission {
+    #[prost(enumeration="super::super::super::super::protocol::permission::PermissionType", tag="1")]
+    pub r#type: i32,
+    #[prost(int32, tag="2")]
+    pub id: i32,
+    #[prost(string, tag="3")]
+    pub name: ::prost::alloc::string::String,
+    /// Sum of the key weights required to sign a transaction.
+    #[prost(int64, tag="4")]
+    pub threshold: i64,
+    #[prost(int32, tag="5")]
+    pub parent_id: i32,
+    #[prost(message, repeated, tag="6")]
+    pub keys: ::prost::alloc::vec::Vec<PermissionKey>,
+    /// Contract types allowed by an active permission, expanded from its operations bitmask.
+    /// Empty for the owner and witness permissions, which are not restricted.
+    #[prost(enumeration="super::super::super::super::protocol::transaction::contract::ContractType", repeated, tag="7")]
+    pub operations: ::prost::alloc::vec::Vec<i32>,
+    /// Raw operations bitmask, hex encoded.
+    #[prost(string, tag="8")]
+    pub operations_mask: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PermissionKey {
+    /// Base58 encoded address of the key.
+    #[prost(string, tag="1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(int64, tag="2")]
+    pub weight: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExchangeEvents {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<ExchangeEvent>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// ExchangeEvent is an operation on a built-in Bancor exchange between two TRC10 assets, or
+/// between TRX and a TRC10 asset. Token ids are TRC10 asset ids, `_` standing for TRX.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExchangeEvent {
+    /// Transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Base58 encoded address of the account operating on the exchange.
+    #[prost(string, tag="4")]
+    pub owner: ::prost::alloc::string::String,
+    /// Id of the exchange. For a creation, it is the id assigned by the chain, as reported in
+    /// `protocol.TransactionInfo`.
+    #[prost(int64, tag="5")]
+    pub exchange_id: i64,
+    #[prost(oneof="exchange_event::Event", tags="6, 7, 8, 9")]
+    pub event: ::core::option::Option<exchange_event::Event>,
+}
+/// Nested message and enum types in `ExchangeEvent`.
+pub mod exchange_event {
+    /// Create is an `ExchangeCreateContract`, funding the exchange with both tokens.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Create {
+        #[prost(string, tag="1")]
+        pub first_token_id: ::prost::alloc::string::String,
+        #[prost(int64, tag="2")]
+        pub first_token_balance: i64,
+        #[prost(string, tag="3")]
+        pub second_token_id: ::prost::alloc::string::String,
+        #[prost(int64, tag="4")]
+        pub second_token_balance: i64,
+    }
+    /// Inject is an `ExchangeInjectContract`, adding liquidity in both tokens.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Inject {
+        #[prost(string, tag="1")]
+        pub token_id: ::prost::alloc::string::String,
+        #[prost(int64, tag="2")]
+        pub quant: i64,
+        /// Amount of the other token of the exchange injected along, as reported by
+        /// `exchange_inject_another_amount`.
+        #[prost(int64, tag="3")]
+        pub another_amount: i64,
+    }
+    /// Withdraw is an `ExchangeWithdrawContract`, removing liquidity in both tokens.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Withdraw {
+        #[prost(string, tag="1")]
+        pub token_id: ::prost::alloc::string::String,
+        #[prost(int64, tag="2")]
+        pub quant: i64,
+        /// Amount of the other token of the exchange withdrawn along, as reported by
+        /// `exchange_withdraw_another_amount`.
+        #[prost(int64, tag="3")]
+        pub another_amount: i64,
+    }
+    /// Transaction is an `ExchangeTransactionContract`, selling a token for the other one.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Transaction {
+        /// Token sold.
+        #[prost(string, tag="1")]
+        pub token_id: ::prost::alloc::string::String,
+        #[prost(int64, tag="2")]
+        pub quant: i64,
+        /// Minimum amount of the other token expected.
+        #[prost(int64, tag="3")]
+        pub expected: i64,
+        /// Amount of the other token received, as reported by `exchange_received_amount`.
+        #[prost(int64, tag="4")]
+        pub received_amount: i64,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="6")]
+        Create(Create),
+        #[prost(message, tag="7")]
+        Inject(Inject),
+        #[prost(message, tag="8")]
+        Withdraw(Withdraw),
+        #[prost(message, tag="9")]
+        Transaction(Transaction),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketEvents {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<MarketEvent>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// MarketEvent is an event of an order of the built-in market between TRC10 assets, or between
+/// TRX and a TRC10 asset. Token ids are TRC10 asset ids, `_` standing for TRX.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketEvent {
+    /// Transaction id, hex encoded.
+    #[prost(string, tag="1")]
+    pub txid: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Base58 encoded address of the order owner, the taker for a fill.
+    #[prost(string, tag="4")]
+    pub owner: ::prost::alloc::string::String,
+    /// Id of the order, hex encoded. For a fill, it is the id of the taker order.
+    #[prost(string, tag="5")]
+    pub order_id: ::prost::alloc::string::String,
+    #[prost(oneof="market_event::Event", tags="6, 7, 8")]
+    pub event: ::core::option::Option<market_event::Event>,
+}
+/// Nested message and enum types in `MarketEvent`.
+pub mod market_event {
+    /// Placed is a `MarketSellAssetContract`, its order id being reported in `protocol.TransactionInfo`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Placed {
+        #[prost(string, tag="1")]
+        pub sell_token_id: ::prost::alloc::string::String,
+        #[prost(int64, tag="2")]
+        pub sell_token_quantity: i64,
+        #[prost(string, tag="3")]
+        pub buy_token_id: ::prost::alloc::string::String,
+        /// Minimum quantity bought for the whole sell quantity, setting the price of the order.
+        #[prost(int64, tag="4")]
+        pub buy_token_quantity: i64,
+    }
+    /// Filled is a match of a newly placed order (the taker) against a resting order (the maker),
+    /// from the `order_details` of `protocol.TransactionInfo`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Filled {
+        /// Id of the maker order, hex encoded.
+        #[prost(string, tag="1")]
+        pub maker_order_id: ::prost::alloc::string::String,
+        /// Quantity sold by the taker.
+        #[prost(int64, tag="2")]
+        pub fill_sell_quantity: i64,
+        /// Quantity bought by the taker.
+        #[prost(int64, tag="3")]
+        pub fill_buy_quantity: i64,
+    }
+    /// Cancelled is a `MarketCancelOrderContract`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Cancelled {
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="6")]
+        Placed(Placed),
+        #[prost(message, tag="7")]
+        Filled(Filled),
+        #[prost(message, tag="8")]
+        Cancelled(Cancelled),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trc10Assets {
+    #[prost(message, repeated, tag="1")]
+    pub assets: ::prost::alloc::vec::Vec<Trc10Asset>,
+    #[prost(message, optional, tag="2")]
+    pub clock: ::core::option::Option<super::super::v1::Clock>,
+}
+/// Trc10Asset is a TRC10 asset as issued by an `AssetIssueContract`, with the changes made
+/// by its latest `UpdateAssetContract`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trc10Asset {
+    /// TRC10 asset id, e.g. `1002000`.
+    #[prost(string, tag="1")]
+    pub id: ::prost::alloc::string::String,
+    /// Base58 encoded address of the issuer.
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub abbr: ::prost::alloc::string::String,
+    /// Number of decimals of the asset amounts.
+    #[prost(int32, tag="5")]
+    pub precision: i32,
+    /// Total supply, in the asset's smallest unit.
+    #[prost(int64, tag="6")]
+    pub total_supply: i64,
+    #[prost(message, repeated, tag="7")]
+    pub frozen_supply: ::prost::alloc::vec::Vec<trc10_asset::FrozenSupply>,
+    #[prost(string, tag="8")]
+    pub url: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub description: ::prost::alloc::string::String,
+    /// Bandwidth each account can use for free to transfer the asset, paid by the issuer.
+    #[prost(int64, tag="10")]
+    pub free_asset_net_limit: i64,
+    /// Total bandwidth all accounts can use for free to transfer the asset.
+    #[prost(int64, tag="11")]
+    pub public_free_asset_net_limit: i64,
+    #[prost(uint64, tag="12")]
+    pub issued_block_number: u64,
+    /// Block of the latest `UpdateAssetContract`, or of the issuance if never updated.
+    #[prost(uint64, tag="13")]
+    pub updated_block_number: u64,
+    /// Issuance rate: `num` units of the asset are sold for every `trx_num` sun.
+    #[prost(int32, tag="14")]
+    pub trx_num: i32,
+    #[prost(int32, tag="15")]
+    pub num: i32,
+}
+/// Nested message and enum types in `Trc10Asset`.
+pub mod trc10_asset {
+    /// FrozenSupply is an amount of the supply locked by the issuer.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct FrozenSupply {
+        #[prost(int64, tag="1")]
+        pub frozen_amount: i64,
+        #[prost(int64, tag="2")]
+        pub frozen_days: i64,
+    }
+}
 // @@protoc_insertion_point(module)
@@ -0,0 +1,95 @@
+use crate::pb::protocol;
+use crate::pb::protocol::transaction::contract::ContractType;
+use crate::pb::sf::substreams::tron::v1::{
+    AccountPermission, PermissionKey, PermissionUpdate, PermissionUpdates, Transactions,
+};
+use crate::utils::{decoded_contracts, tron_address_to_base58};
+
+#[substreams::handlers::map]
+fn map_permission_updates(
+    transactions: Transactions,
+) -> Result<PermissionUpdates, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+    let mut updates = Vec::new();
+
+    for transaction in &transactions.transactions {
+        for contract in decoded_contracts::<protocol::AccountPermissionUpdateContract>(
+            transaction,
+            ContractType::AccountPermissionUpdateContract,
+        ) {
+            updates.push(PermissionUpdate {
+                txid: hex::encode(&transaction.txid),
+                block_number: clock.number,
+                block_time: clock.timestamp,
+                owner: tron_address_to_base58(&contract.owner_address),
+                owner_permission: contract.owner.map(account_permission),
+                witness_permission: contract.witness.map(account_permission),
+                active_permissions: contract
+                    .actives
+                    .into_iter()
+                    .map(account_permission)
+                    .collect(),
+            });
+        }
+    }
+
+    Ok(PermissionUpdates {
+        updates,
+        clock: Some(clock),
+    })
+}
+
+fn account_permission(permission: protocol::Permission) -> AccountPermission {
+    AccountPermission {
+        r#type: permission.r#type,
+        id: permission.id,
+        name: permission.permission_name,
+        threshold: permission.threshold,
+        parent_id: permission.parent_id,
+        keys: permission
+            .keys
+            .iter()
+            .map(|key| PermissionKey {
+                address: tron_address_to_base58(&key.address),
+                weight: key.weight,
+            })
+            .collect(),
+        operations: allowed_contract_types(&permission.operations)
+            .into_iter()
+            .map(|contract_type| contract_type as i32)
+            .collect(),
+        operations_mask: hex::encode(&permission.operations),
+    }
+}
+
+/// Expands a permission operations bitmask into the contract types it allows. Bit `n`, counting
+/// from the least significant bit of the first byte, allows the contract type numbered `n`.
+pub fn allowed_contract_types(operations: &[u8]) -> Vec<ContractType> {
+    (0..operations.len() * 8)
+        .filter(|bit| operations[bit / 8] & (1 << (bit % 8)) != 0)
+        .filter_map(|bit| ContractType::try_from(bit as i32).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allowed_contract_types_from_bitmask() {
+        // TransferContract (1), TransferAssetContract (2) and TriggerSmartContract (31)
+        let mut operations = vec![0u8; 32];
+        operations[0] = 0b0000_0110;
+        operations[3] = 0b1000_0000;
+
+        assert_eq!(
+            allowed_contract_types(&operations),
+            vec![
+                ContractType::TransferContract,
+                ContractType::TransferAssetContract,
+                ContractType::TriggerSmartContract,
+            ]
+        );
+        assert!(allowed_contract_types(&[]).is_empty());
+    }
+}
@@ -0,0 +1,68 @@
+use crate::pb::protocol;
+use crate::pb::protocol::transaction::contract::ContractType;
+use crate::pb::sf::substreams::tron::v1::{ResourceUsage, ResourceUsageAggregate, Transactions};
+use crate::utils::{decoded_contracts, extract_from_address, tron_address_to_base58};
+use std::collections::BTreeMap;
+
+#[substreams::handlers::map]
+fn map_resource_usage(
+    transactions: Transactions,
+) -> Result<ResourceUsage, substreams::errors::Error> {
+    let mut by_contract: BTreeMap<String, ResourceUsageAggregate> = BTreeMap::new();
+    let mut by_caller: BTreeMap<String, ResourceUsageAggregate> = BTreeMap::new();
+
+    for transaction in &transactions.transactions {
+        let Some(receipt) = transaction.info.as_ref().and_then(|info| info.receipt) else {
+            continue;
+        };
+
+        let caller = transaction.contracts.iter().find_map(|contract| {
+            let parameter = contract.parameter.as_ref()?;
+            extract_from_address(contract.r#type, parameter)
+        });
+        if let Some(caller) = caller {
+            accumulate(&mut by_caller, tron_address_to_base58(&caller), &receipt);
+        }
+
+        if let Some(trigger) = decoded_contracts::<protocol::TriggerSmartContract>(
+            transaction,
+            ContractType::TriggerSmartContract,
+        )
+        .next()
+        {
+            accumulate(
+                &mut by_contract,
+                tron_address_to_base58(&trigger.contract_address),
+                &receipt,
+            );
+        }
+    }
+
+    Ok(ResourceUsage {
+        by_contract: by_contract.into_values().collect(),
+        by_caller: by_caller.into_values().collect(),
+        clock: transactions.clock,
+    })
+}
+
+fn accumulate(
+    aggregates: &mut BTreeMap<String, ResourceUsageAggregate>,
+    address: String,
+    receipt: &protocol::ResourceReceipt,
+) {
+    let aggregate = aggregates
+        .entry(address.clone())
+        .or_insert_with(|| ResourceUsageAggregate {
+            address,
+            ..Default::default()
+        });
+
+    aggregate.transaction_count += 1;
+    aggregate.energy_usage += receipt.energy_usage;
+    aggregate.energy_fee += receipt.energy_fee;
+    aggregate.origin_energy_usage += receipt.origin_energy_usage;
+    aggregate.energy_usage_total += receipt.energy_usage_total;
+    aggregate.energy_penalty_total += receipt.energy_penalty_total;
+    aggregate.net_usage += receipt.net_usage;
+    aggregate.net_fee += receipt.net_fee;
+}
@@ -0,0 +1,439 @@
+use crate::pb::protocol;
+use crate::pb::protocol::transaction::contract::ContractType;
+use crate::pb::sf::substreams::tron::v1::stake_v2_event::{
+    CancelAllUnfreeze, Event, Freeze, Unfreeze, WithdrawExpireUnfreeze,
+};
+use crate::pb::sf::substreams::tron::v1::{
+    stake_v1_event, DelegationDelta, DelegationDeltas, DelegationEvent, DelegationEvents,
+    DelegationLock, StakeV1Event, StakeV1Events, StakeV2Event, StakeV2Events, Transactions,
+};
+use crate::pb::sf::substreams::v1::Clock;
+use crate::utils::{decode_param, decoded_contracts, tron_address_to_base58};
+use protocol::ResourceCode;
+use substreams::store::{
+    DeltaInt64, Deltas, StoreAdd, StoreAddInt64, StoreDelete, StoreGet, StoreGetInt64,
+    StoreGetProto, StoreNew, StoreSet, StoreSetProto,
+};
+
+/// Lock period applied by java-tron to a locked delegation without one: 3 days of blocks.
+const DEFAULT_LOCK_PERIOD_BLOCKS: u64 = 3 * 24 * 60 * 60 / 3;
+
+#[substreams::handlers::map]
+fn map_stake_v2_events(
+    transactions: Transactions,
+) -> Result<StakeV2Events, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+    let mut events = Vec::new();
+
+    for transaction in &transactions.transactions {
+        let info = transaction.info.as_ref();
+        let withdraw_expire_amount = info.map_or(0, |info| info.withdraw_expire_amount);
+        let unfreeze_amount = info.map_or(0, |info| info.unfreeze_amount);
+
+        for contract in &transaction.contracts {
+            let Some(ref parameter) = contract.parameter else {
+                continue;
+            };
+
+            let decoded = match ContractType::try_from(contract.r#type) {
+                Ok(ContractType::FreezeBalanceV2Contract) => {
+                    decode_param::<protocol::FreezeBalanceV2Contract>(parameter).map(|c| {
+                        let event = Event::Freeze(Freeze {
+                            resource: c.resource,
+                            amount: c.frozen_balance,
+                        });
+                        (c.owner_address, event)
+                    })
+                }
+                Ok(ContractType::UnfreezeBalanceV2Contract) => {
+                    decode_param::<protocol::UnfreezeBalanceV2Contract>(parameter).map(|c| {
+                        let event = Event::Unfreeze(Unfreeze {
+                            resource: c.resource,
+                            amount: c.unfreeze_balance,
+                            withdraw_expire_amount,
+                            unfreeze_amount,
+                        });
+                        (c.owner_address, event)
+                    })
+                }
+                Ok(ContractType::WithdrawExpireUnfreezeContract) => {
+                    decode_param::<protocol::WithdrawExpireUnfreezeContract>(parameter).map(|c| {
+                        let event = Event::WithdrawExpireUnfreeze(WithdrawExpireUnfreeze {
+                            amount: withdraw_expire_amount,
+                        });
+                        (c.owner_address, event)
+                    })
+                }
+                Ok(ContractType::CancelAllUnfreezeV2Contract) => {
+                    decode_param::<protocol::CancelAllUnfreezeV2Contract>(parameter).map(|c| {
+                        let event = Event::CancelAllUnfreeze(CancelAllUnfreeze {
+                            cancelled_amounts: info
+                                .map(|info| info.cancel_unfreeze_v2_amount.clone())
+                                .unwrap_or_default(),
+                            withdraw_expire_amount,
+                        });
+                        (c.owner_address, event)
+                    })
+                }
+                _ => None,
+            };
+
+            if let Some((owner_address, event)) = decoded {
+                events.push(StakeV2Event {
+                    txid: hex::encode(&transaction.txid),
+                    block_number: clock.number,
+                    block_time: clock.timestamp,
+                    owner: tron_address_to_base58(&owner_address),
+                    event: Some(event),
+                });
+            }
+        }
+    }
+
+    Ok(StakeV2Events {
+        events,
+        clock: Some(clock),
+    })
+}
+
+#[substreams::handlers::map]
+fn map_stake_v1_events(
+    transactions: Transactions,
+) -> Result<StakeV1Events, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+    let mut events = Vec::new();
+
+    for transaction in &transactions.transactions {
+        let unfreeze_amount = transaction
+            .info
+            .as_ref()
+            .map_or(0, |info| info.unfreeze_amount);
+
+        for contract in &transaction.contracts {
+            let Some(ref parameter) = contract.parameter else {
+                continue;
+            };
+
+            let decoded = match ContractType::try_from(contract.r#type) {
+                Ok(ContractType::FreezeBalanceContract) => {
+                    decode_param::<protocol::FreezeBalanceContract>(parameter).map(|c| {
+                        let event = stake_v1_event::Event::Freeze(stake_v1_event::Freeze {
+                            resource: c.resource,
+                            frozen_balance: c.frozen_balance,
+                            frozen_duration: c.frozen_duration,
+                            receiver: receiver_to_base58(&c.receiver_address),
+                        });
+                        (c.owner_address, event)
+                    })
+                }
+                Ok(ContractType::UnfreezeBalanceContract) => {
+                    decode_param::<protocol::UnfreezeBalanceContract>(parameter).map(|c| {
+                        let event = stake_v1_event::Event::Unfreeze(stake_v1_event::Unfreeze {
+                            resource: c.resource,
+                            receiver: receiver_to_base58(&c.receiver_address),
+                            unfreeze_amount,
+                        });
+                        (c.owner_address, event)
+                    })
+                }
+                _ => None,
+            };
+
+            if let Some((owner_address, event)) = decoded {
+                events.push(StakeV1Event {
+                    txid: hex::encode(&transaction.txid),
+                    block_number: clock.number,
+                    block_time: clock.timestamp,
+                    owner: tron_address_to_base58(&owner_address),
+                    event: Some(event),
+                });
+            }
+        }
+    }
+
+    Ok(StakeV1Events {
+        events,
+        clock: Some(clock),
+    })
+}
+
+#[substreams::handlers::map]
+fn map_delegation_events(
+    transactions: Transactions,
+) -> Result<DelegationEvents, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+    let mut events = Vec::new();
+
+    for transaction in &transactions.transactions {
+        let txid = hex::encode(&transaction.txid);
+
+        for contract in decoded_contracts::<protocol::DelegateResourceContract>(
+            transaction,
+            ContractType::DelegateResourceContract,
+        ) {
+            events.push(DelegationEvent {
+                txid: txid.clone(),
+                block_number: clock.number,
+                block_time: clock.timestamp,
+                delegator: tron_address_to_base58(&contract.owner_address),
+                receiver: tron_address_to_base58(&contract.receiver_address),
+                resource: contract.resource,
+                balance: contract.balance,
+                undelegate: false,
+                lock: contract.lock,
+                lock_period: contract.lock_period,
+            });
+        }
+
+        for contract in decoded_contracts::<protocol::UnDelegateResourceContract>(
+            transaction,
+            ContractType::UnDelegateResourceContract,
+        ) {
+            events.push(DelegationEvent {
+                txid: txid.clone(),
+                block_number: clock.number,
+                block_time: clock.timestamp,
+                delegator: tron_address_to_base58(&contract.owner_address),
+                receiver: tron_address_to_base58(&contract.receiver_address),
+                resource: contract.resource,
+                balance: contract.balance,
+                undelegate: true,
+                lock: false,
+                lock_period: 0,
+            });
+        }
+    }
+
+    Ok(DelegationEvents {
+        events,
+        clock: Some(clock),
+    })
+}
+
+/// Keeps the lock of the locked delegations per (delegator, receiver, resource).
+#[substreams::handlers::store]
+fn store_delegation_locks(events: DelegationEvents, store: StoreSetProto<DelegationLock>) {
+    for (ordinal, event) in events.events.iter().enumerate() {
+        if event.undelegate || !event.lock {
+            continue;
+        }
+        store.set(
+            ordinal as u64,
+            delegation_key(&event.delegator, &event.receiver, resource(event), true),
+            &DelegationLock {
+                block_number: event.block_number,
+                expire_block_number: lock_expiry(event.block_number, event.lock_period),
+            },
+        );
+    }
+}
+
+/// Keeps the locked staked amount delegated per (delegator, receiver, resource), in sun, reset
+/// once unlocked. Only used to know the amount moved to the unlocked balance by
+/// `store_delegated_balances`.
+#[substreams::handlers::store]
+fn store_locked_balances(
+    events: DelegationEvents,
+    locks: StoreGetProto<DelegationLock>,
+    store: StoreAddInt64,
+) {
+    for (ordinal, event) in events.events.iter().enumerate() {
+        for resource in expired_locks(event, ordinal as u64, &locks) {
+            store.delete_prefix(
+                ordinal as i64,
+                &delegation_key(&event.delegator, &event.receiver, resource, true),
+            );
+        }
+        if !event.undelegate && event.lock {
+            store.add(
+                ordinal as u64,
+                delegation_key(&event.delegator, &event.receiver, resource(event), true),
+                event.balance,
+            );
+        }
+    }
+}
+
+/// Keeps the staked amount delegated per (delegator, receiver, resource), locked and unlocked
+/// apart, in sun. As on chain, every delegation first moves the expired locked balances of the
+/// two accounts to the unlocked ones, and undelegations only take from the unlocked balance.
+#[substreams::handlers::store]
+fn store_delegated_balances(
+    events: DelegationEvents,
+    locks: StoreGetProto<DelegationLock>,
+    locked_balances: StoreGetInt64,
+    store: StoreAddInt64,
+) {
+    for (ordinal, event) in events.events.iter().enumerate() {
+        for resource in expired_locks(event, ordinal as u64, &locks) {
+            let locked_key = delegation_key(&event.delegator, &event.receiver, resource, true);
+            let unlocked = get_before(&locked_balances, ordinal as u64, &locked_key).unwrap_or(0);
+            if unlocked != 0 {
+                store.add(ordinal as u64, locked_key, -unlocked);
+                store.add(
+                    ordinal as u64,
+                    delegation_key(&event.delegator, &event.receiver, resource, false),
+                    unlocked,
+                );
+            }
+        }
+
+        let balance = if event.undelegate {
+            -event.balance
+        } else {
+            event.balance
+        };
+        store.add(
+            ordinal as u64,
+            delegation_key(
+                &event.delegator,
+                &event.receiver,
+                resource(event),
+                event.lock,
+            ),
+            balance,
+        );
+    }
+}
+
+#[substreams::handlers::map]
+fn map_delegation_deltas(
+    clock: Clock,
+    balances: Deltas<DeltaInt64>,
+    locks: StoreGetProto<DelegationLock>,
+) -> Result<DelegationDeltas, substreams::errors::Error> {
+    let mut deltas = Vec::new();
+
+    for delta in balances.deltas {
+        let Some((delegator, receiver, resource, locked)) = parse_delegation_key(&delta.key) else {
+            continue;
+        };
+
+        deltas.push(DelegationDelta {
+            delegator: delegator.to_string(),
+            receiver: receiver.to_string(),
+            resource: resource as i32,
+            old_balance: delta.old_value,
+            new_balance: delta.new_value,
+            lock: locked.then(|| locks.get_last(&delta.key)).flatten(),
+            locked,
+        });
+    }
+
+    Ok(DelegationDeltas {
+        deltas,
+        clock: Some(clock),
+    })
+}
+
+/// Returns the resources whose locked delegation between the accounts of an event had expired
+/// before it. java-tron unlocks them, for both resources, before applying any delegation or
+/// undelegation between the two accounts.
+fn expired_locks(
+    event: &DelegationEvent,
+    ordinal: u64,
+    locks: &StoreGetProto<DelegationLock>,
+) -> Vec<ResourceCode> {
+    [ResourceCode::Bandwidth, ResourceCode::Energy]
+        .into_iter()
+        .filter(|&resource| {
+            let key = delegation_key(&event.delegator, &event.receiver, resource, true);
+            get_before(locks, ordinal, &key)
+                .is_some_and(|lock| lock.expire_block_number < event.block_number)
+        })
+        .collect()
+}
+
+/// Returns the value of a key before the delta at `ordinal` was applied.
+fn get_before<T, S: StoreGet<T>>(store: &S, ordinal: u64, key: &str) -> Option<T> {
+    match ordinal {
+        0 => store.get_first(key),
+        _ => store.get_at(ordinal - 1, key),
+    }
+}
+
+/// Returns the block after which a delegation locked at `block_number` is unlocked.
+fn lock_expiry(block_number: u64, lock_period: i64) -> u64 {
+    let lock_period = if lock_period > 0 {
+        lock_period as u64
+    } else {
+        DEFAULT_LOCK_PERIOD_BLOCKS
+    };
+    block_number + lock_period
+}
+
+fn resource(event: &DelegationEvent) -> ResourceCode {
+    ResourceCode::try_from(event.resource).unwrap_or(ResourceCode::Bandwidth)
+}
+
+fn delegation_key(delegator: &str, receiver: &str, resource: ResourceCode, locked: bool) -> String {
+    format!(
+        "delegation:{}:{}:{}:{}",
+        delegator,
+        receiver,
+        resource.as_str_name(),
+        if locked { "locked" } else { "unlocked" }
+    )
+}
+
+fn parse_delegation_key(key: &str) -> Option<(&str, &str, ResourceCode, bool)> {
+    let mut parts = key.strip_prefix("delegation:")?.split(':');
+    let delegator = parts.next()?;
+    let receiver = parts.next()?;
+    let resource = ResourceCode::from_str_name(parts.next()?)?;
+    let locked = match parts.next()? {
+        "locked" => true,
+        "unlocked" => false,
+        _ => return None,
+    };
+    Some((delegator, receiver, resource, locked))
+}
+
+/// Stake 1.0 leaves `receiver_address` empty when the resources are frozen for the owner itself.
+fn receiver_to_base58(address: &[u8]) -> String {
+    if address.is_empty() {
+        String::new()
+    } else {
+        tron_address_to_base58(address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delegation_key_round_trip() {
+        let delegator = "TMAP4Dnyh2og7bzW6HxZfuZqRSTHsVDKRT";
+        let receiver = "THxNDMy3y9NP7Bfat9CTKmZ4PFfj1v4gWa";
+
+        for locked in [true, false] {
+            let key = delegation_key(delegator, receiver, ResourceCode::Energy, locked);
+            assert_eq!(
+                parse_delegation_key(&key),
+                Some((delegator, receiver, ResourceCode::Energy, locked))
+            );
+        }
+        assert_eq!(
+            delegation_key(delegator, receiver, ResourceCode::Bandwidth, false),
+            format!("delegation:{}:{}:BANDWIDTH:unlocked", delegator, receiver)
+        );
+        assert_eq!(parse_delegation_key("delegation:a:b:ENERGY"), None);
+        assert_eq!(parse_delegation_key("allowance:a:b:c:d"), None);
+    }
+
+    #[test]
+    fn lock_expiry_defaults_to_three_days() {
+        assert_eq!(lock_expiry(1_000, 100), 1_100);
+        assert_eq!(lock_expiry(1_000, 0), 1_000 + 86_400);
+    }
+
+    #[test]
+    fn receiver_to_base58_keeps_self_freezes_empty() {
+        assert_eq!(receiver_to_base58(&[]), "");
+        assert_eq!(
+            receiver_to_base58(&hex::decode("41a614f803b6fd780986a42c78ec9c7f77e6ded13c").unwrap()),
+            "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"
+        );
+    }
+}
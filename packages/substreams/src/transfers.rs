@@ -0,0 +1,164 @@
+use crate::pb::protocol;
+use crate::pb::protocol::transaction::contract::ContractType;
+use crate::pb::sf::substreams::tron::v1::{
+    Transactions, Trc10Transfer, Trc10Transfers, TrxTransfer, TrxTransfers,
+};
+use crate::pb::sf::substreams::v1::Clock;
+use crate::pb::sf::tron::r#type::v1::Transaction;
+use crate::utils::{contract_execution_failed, decoded_contracts, tron_address_to_base58};
+
+#[substreams::handlers::map]
+fn map_trx_transfers(
+    transactions: Transactions,
+) -> Result<TrxTransfers, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+
+    Ok(TrxTransfers {
+        transfers: transactions
+            .transactions
+            .iter()
+            .flat_map(|transaction| trx_transfers(transaction, &clock))
+            .collect(),
+        clock: Some(clock),
+    })
+}
+
+#[substreams::handlers::map]
+fn map_trc10_transfers(
+    transactions: Transactions,
+) -> Result<Trc10Transfers, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+
+    Ok(Trc10Transfers {
+        transfers: transactions
+            .transactions
+            .iter()
+            .flat_map(|transaction| trc10_transfers(transaction, &clock))
+            .collect(),
+        clock: Some(clock),
+    })
+}
+
+/// Returns the TRX transfers made by the `TransferContract`s of a transaction.
+pub fn trx_transfers(transaction: &Transaction, clock: &Clock) -> Vec<TrxTransfer> {
+    let fee = transaction.info.as_ref().map_or(0, |info| info.fee);
+
+    decoded_contracts::<protocol::TransferContract>(transaction, ContractType::TransferContract)
+        .map(|contract| TrxTransfer {
+            txid: hex::encode(&transaction.txid),
+            block_number: clock.number,
+            block_time: clock.timestamp,
+            from: tron_address_to_base58(&contract.owner_address),
+            to: tron_address_to_base58(&contract.to_address),
+            amount: contract.amount,
+            fee,
+        })
+        .collect()
+}
+
+/// Returns the TRC10 transfers made by the `TransferAssetContract`s of a transaction,
+/// and by its successful `TriggerSmartContract`s carrying a `call_token_value`.
+pub fn trc10_transfers(transaction: &Transaction, clock: &Clock) -> Vec<Trc10Transfer> {
+    let txid = hex::encode(&transaction.txid);
+    let mut transfers = Vec::new();
+
+    for contract in decoded_contracts::<protocol::TransferAssetContract>(
+        transaction,
+        ContractType::TransferAssetContract,
+    ) {
+        transfers.push(Trc10Transfer {
+            txid: txid.clone(),
+            block_number: clock.number,
+            block_time: clock.timestamp,
+            contract_type: ContractType::TransferAssetContract as i32,
+            asset_id: String::from_utf8_lossy(&contract.asset_name).into_owned(),
+            from: tron_address_to_base58(&contract.owner_address),
+            to: tron_address_to_base58(&contract.to_address),
+            amount: contract.amount,
+        });
+    }
+
+    // Smart contract calls can carry a TRC10 amount alongside (or instead of) TRX, refunded when
+    // the call fails
+    if contract_execution_failed(transaction) {
+        return transfers;
+    }
+    for contract in decoded_contracts::<protocol::TriggerSmartContract>(
+        transaction,
+        ContractType::TriggerSmartContract,
+    ) {
+        if contract.call_token_value == 0 || contract.token_id == 0 {
+            continue;
+        }
+
+        transfers.push(Trc10Transfer {
+            txid: txid.clone(),
+            block_number: clock.number,
+            block_time: clock.timestamp,
+            contract_type: ContractType::TriggerSmartContract as i32,
+            asset_id: contract.token_id.to_string(),
+            from: tron_address_to_base58(&contract.owner_address),
+            to: tron_address_to_base58(&contract.contract_address),
+            amount: contract.call_token_value,
+        });
+    }
+
+    transfers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pb::protocol::transaction::result::ContractResult;
+    use prost::Message;
+    use prost_types::Any;
+
+    fn call_transaction(result: ContractResult) -> Transaction {
+        let contract = protocol::TriggerSmartContract {
+            owner_address: vec![0x41; 21],
+            contract_address: vec![0x42; 21],
+            call_token_value: 500,
+            token_id: 1_002_000,
+            ..Default::default()
+        };
+        Transaction {
+            contracts: vec![protocol::transaction::Contract {
+                r#type: ContractType::TriggerSmartContract as i32,
+                parameter: Some(Any {
+                    type_url: "type.googleapis.com/protocol.TriggerSmartContract".to_string(),
+                    value: contract.encode_to_vec(),
+                }),
+                ..Default::default()
+            }],
+            info: Some(protocol::TransactionInfo {
+                receipt: Some(protocol::ResourceReceipt {
+                    result: result as i32,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn call_token_value_of_successful_calls_only() {
+        let transfers = trc10_transfers(
+            &call_transaction(ContractResult::Success),
+            &Clock::default(),
+        );
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(
+            transfers[0].contract_type,
+            ContractType::TriggerSmartContract as i32
+        );
+        assert_eq!(transfers[0].asset_id, "1002000");
+        assert_eq!(transfers[0].to, tron_address_to_base58(&[0x42; 21]));
+        assert_eq!(transfers[0].amount, 500);
+
+        // Refunded by the chain
+        for result in [ContractResult::Revert, ContractResult::OutOfEnergy] {
+            assert!(trc10_transfers(&call_transaction(result), &Clock::default()).is_empty());
+        }
+    }
+}
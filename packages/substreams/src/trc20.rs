@@ -0,0 +1,248 @@
+use crate::pb::sf::substreams::tron::v1::{
+    Transactions, Trc20Allowance, Trc20Approval, Trc20Approvals, Trc20Transfer, Trc20Transfers,
+};
+use crate::pb::sf::substreams::v1::Clock;
+use crate::pb::sf::tron::r#type::v1::Transaction;
+use crate::utils::{evm_address_to_base58, uint256_to_decimal};
+use substreams::hex;
+use substreams::store::{StoreDelete, StoreNew, StoreSet, StoreSetProto};
+
+/// keccak256("Transfer(address,address,uint256)")
+pub const TRANSFER_TOPIC: [u8; 32] =
+    hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+/// keccak256("Approval(address,address,uint256)")
+pub const APPROVAL_TOPIC: [u8; 32] =
+    hex!("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925");
+
+#[substreams::handlers::map]
+fn map_trc20_transfers(
+    transactions: Transactions,
+) -> Result<Trc20Transfers, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+
+    Ok(Trc20Transfers {
+        transfers: transactions
+            .transactions
+            .iter()
+            .flat_map(|transaction| trc20_transfers(transaction, &clock))
+            .collect(),
+        clock: Some(clock),
+    })
+}
+
+/// Returns the TRC20 `Transfer` events found in the logs of a transaction.
+pub fn trc20_transfers(transaction: &Transaction, clock: &Clock) -> Vec<Trc20Transfer> {
+    let Some(ref info) = transaction.info else {
+        return Vec::new();
+    };
+    let mut transfers = Vec::new();
+
+    for (log_index, log) in info.log.iter().enumerate() {
+        // TRC721 shares the same signature but indexes the token id as a 4th topic
+        if log.topics.len() != 3 || log.topics[0] != TRANSFER_TOPIC || log.data.len() != 32 {
+            continue;
+        }
+
+        transfers.push(Trc20Transfer {
+            txid: hex::encode(&transaction.txid),
+            block_number: clock.number,
+            block_time: clock.timestamp,
+            token_address: evm_address_to_base58(&log.address),
+            from: evm_address_to_base58(&log.topics[1]),
+            to: evm_address_to_base58(&log.topics[2]),
+            amount: uint256_to_decimal(&log.data),
+            log_index: log_index as u32,
+        });
+    }
+
+    transfers
+}
+
+#[substreams::handlers::map]
+fn map_trc20_approvals(
+    transactions: Transactions,
+) -> Result<Trc20Approvals, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+
+    Ok(Trc20Approvals {
+        approvals: transactions
+            .transactions
+            .iter()
+            .flat_map(|transaction| trc20_approvals(transaction, &clock))
+            .collect(),
+        clock: Some(clock),
+    })
+}
+
+/// Returns the TRC20 `Approval` events found in the logs of a transaction.
+pub fn trc20_approvals(transaction: &Transaction, clock: &Clock) -> Vec<Trc20Approval> {
+    let Some(ref info) = transaction.info else {
+        return Vec::new();
+    };
+    let mut approvals = Vec::new();
+
+    for (log_index, log) in info.log.iter().enumerate() {
+        if log.topics.len() != 3 || log.topics[0] != APPROVAL_TOPIC || log.data.len() != 32 {
+            continue;
+        }
+
+        approvals.push(Trc20Approval {
+            txid: hex::encode(&transaction.txid),
+            block_number: clock.number,
+            block_time: clock.timestamp,
+            token_address: evm_address_to_base58(&log.address),
+            owner: evm_address_to_base58(&log.topics[1]),
+            spender: evm_address_to_base58(&log.topics[2]),
+            amount: uint256_to_decimal(&log.data),
+            log_index: log_index as u32,
+            unlimited: log.data.iter().all(|b| *b == 0xff),
+            revoked: log.data.iter().all(|b| *b == 0),
+        });
+    }
+
+    approvals
+}
+
+/// Keeps the current allowance per (token, owner, spender). Revoked allowances are removed.
+#[substreams::handlers::store]
+fn store_trc20_allowances(approvals: Trc20Approvals, store: StoreSetProto<Trc20Allowance>) {
+    for (ordinal, approval) in approvals.approvals.into_iter().enumerate() {
+        let key = allowance_key(&approval.token_address, &approval.owner, &approval.spender);
+
+        match allowance(approval) {
+            Some(allowance) => store.set(ordinal as u64, key, &allowance),
+            None => store.delete_prefix(ordinal as i64, &key),
+        }
+    }
+}
+
+/// Returns the allowance set by an approval, or `None` when the approval revokes it.
+fn allowance(approval: Trc20Approval) -> Option<Trc20Allowance> {
+    if approval.revoked {
+        return None;
+    }
+
+    Some(Trc20Allowance {
+        token_address: approval.token_address,
+        owner: approval.owner,
+        spender: approval.spender,
+        amount: approval.amount,
+        unlimited: approval.unlimited,
+        txid: approval.txid,
+        block_number: approval.block_number,
+        block_time: approval.block_time,
+    })
+}
+
+pub fn allowance_key(token_address: &str, owner: &str, spender: &str) -> String {
+    format!("allowance:{}:{}:{}", token_address, owner, spender)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pb::protocol;
+    use crate::pb::protocol::transaction_info::Log;
+
+    /// Builds a log of `signature` from 0x11... to 0x22..., keeping the first `topics` topics.
+    fn event_log(signature: [u8; 32], topics: usize, data: Vec<u8>) -> Log {
+        let mut from = vec![0u8; 12];
+        from.extend_from_slice(&[0x11; 20]);
+        let mut to = vec![0u8; 12];
+        to.extend_from_slice(&[0x22; 20]);
+
+        Log {
+            address: vec![0x33; 20],
+            topics: [signature.to_vec(), from, to, vec![0u8; 32]]
+                .into_iter()
+                .take(topics)
+                .collect(),
+            data,
+        }
+    }
+
+    #[test]
+    fn transfers_and_malformed_logs() {
+        let mut amount = vec![0u8; 32];
+        amount[31] = 0x2a;
+        let transaction = Transaction {
+            info: Some(protocol::TransactionInfo {
+                log: vec![
+                    // TRC721 transfer, indexing the token id
+                    event_log(TRANSFER_TOPIC, 4, vec![]),
+                    event_log(TRANSFER_TOPIC, 3, vec![0u8; 31]),
+                    event_log(TRANSFER_TOPIC, 3, amount),
+                    event_log(APPROVAL_TOPIC, 3, vec![0u8; 32]),
+                ],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let transfers = trc20_transfers(&transaction, &Clock::default());
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(
+            transfers[0].token_address,
+            evm_address_to_base58(&[0x33; 20])
+        );
+        assert_eq!(transfers[0].from, evm_address_to_base58(&[0x11; 20]));
+        assert_eq!(transfers[0].to, evm_address_to_base58(&[0x22; 20]));
+        assert_eq!(transfers[0].amount, "42");
+        assert_eq!(transfers[0].log_index, 2);
+    }
+
+    #[test]
+    fn approvals_flags_and_malformed_logs() {
+        let transaction = Transaction {
+            info: Some(protocol::TransactionInfo {
+                log: vec![
+                    event_log(APPROVAL_TOPIC, 3, vec![0xff; 32]),
+                    event_log(APPROVAL_TOPIC, 3, vec![0u8; 32]),
+                    // TRC721 approval, indexing the token id
+                    event_log(APPROVAL_TOPIC, 4, vec![]),
+                    event_log(APPROVAL_TOPIC, 3, vec![0u8; 31]),
+                ],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let approvals = trc20_approvals(&transaction, &Clock::default());
+        assert_eq!(approvals.len(), 2);
+
+        let unlimited = &approvals[0];
+        assert!(unlimited.unlimited && !unlimited.revoked);
+        assert_eq!(
+            unlimited.amount,
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert_eq!(unlimited.owner, evm_address_to_base58(&[0x11; 20]));
+        assert_eq!(unlimited.spender, evm_address_to_base58(&[0x22; 20]));
+        assert_eq!(unlimited.log_index, 0);
+
+        let revoked = &approvals[1];
+        assert!(revoked.revoked && !revoked.unlimited);
+        assert_eq!(revoked.amount, "0");
+        assert_eq!(revoked.log_index, 1);
+    }
+
+    #[test]
+    fn revoked_approval_removes_allowance() {
+        let transaction = Transaction {
+            info: Some(protocol::TransactionInfo {
+                log: vec![
+                    event_log(APPROVAL_TOPIC, 3, vec![0xff; 32]),
+                    event_log(APPROVAL_TOPIC, 3, vec![0u8; 32]),
+                ],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut approvals = trc20_approvals(&transaction, &Clock::default()).into_iter();
+
+        let unlimited = allowance(approvals.next().unwrap()).unwrap();
+        assert!(unlimited.unlimited);
+        assert_eq!(allowance(approvals.next().unwrap()), None);
+    }
+}
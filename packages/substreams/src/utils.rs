@@ -1,22 +1,55 @@
 use crate::pb::protocol;
-use crate::pb::sf::tron::r#type::v1::ResponseCode;
-use bs58;
+use crate::pb::sf::tron::r#type::v1::{ResponseCode, Transaction};
+use num_bigint::BigUint;
 use prost::Message;
 use prost_types::Any;
 use protocol::transaction::contract::ContractType;
+use protocol::transaction::result::ContractResult;
 use sha2::{Digest, Sha256};
 use std::convert::TryFrom;
 
+/// Prefix byte of every Tron address.
+pub const TRON_ADDRESS_PREFIX: u8 = 0x41;
+
 pub fn transaction_failed(status: i32) -> bool {
     status != ResponseCode::Success as i32
 }
 
+/// Returns whether the smart contract execution of a `TriggerSmartContract` or `CreateSmartContract`
+/// failed (reverted, ran out of energy, ...). Such a transaction is still part of the block, but
+/// its call values are refunded and only its fee is charged.
+pub fn contract_execution_failed(transaction: &Transaction) -> bool {
+    transaction.info.as_ref().is_some_and(|info| {
+        info.result == protocol::transaction_info::Code::Failed as i32
+            || info
+                .receipt
+                .is_some_and(|receipt| receipt.result != ContractResult::Success as i32)
+    })
+}
+
+/// Decodes a contract parameter into its typed protocol struct.
+/// Returns None if the parameter cannot be decoded as `T`.
+pub fn decode_param<T: Message + Default>(parameter: &Any) -> Option<T> {
+    T::decode(&parameter.value[..]).ok()
+}
+
+/// Decodes every contract of the given type in a transaction into its typed protocol struct.
+/// Contracts without a parameter, or whose parameter cannot be decoded, are skipped.
+pub fn decoded_contracts<T: Message + Default>(
+    transaction: &Transaction,
+    contract_type: ContractType,
+) -> impl Iterator<Item = T> + '_ {
+    transaction
+        .contracts
+        .iter()
+        .filter(move |contract| contract.r#type == contract_type as i32)
+        .filter_map(|contract| contract.parameter.as_ref().and_then(decode_param))
+}
+
 /// Macro to extract parameter from a contract. Used to extract the from and to addresses from a contract.
 macro_rules! extract_param {
     ($struct_type:ty, $parameter:expr, $field:ident) => {
-        <$struct_type>::decode(&$parameter.value[..])
-            .ok()
-            .map(|c| c.$field)
+        decode_param::<$struct_type>($parameter).map(|c| c.$field)
     };
 }
 
@@ -203,15 +236,15 @@ pub fn extract_to_address(contract_type: i32, parameter: &Any) -> Option<Vec<u8>
             parameter,
             receiver_address
         ),
-        // TODO: Is this the correct to address?
-        // https://buf.build/streamingfast/tron-protocol/docs/main:protocol#protocol.UnfreezeBalanceContract
+        // Stake 1.0 unfreezes reclaim the resources delegated to `receiver_address`, empty when
+        // they were frozen for the owner itself
         ContractType::UnfreezeBalanceContract => extract_param!(
             protocol::UnfreezeBalanceContract,
             parameter,
             receiver_address
         ),
-        // TODO: Is this the correct to address?
-        // https://buf.build/streamingfast/tron-protocol/docs/main:protocol#protocol.FreezeBalanceContract
+        // Stake 1.0 delegates the frozen resources to `receiver_address`, which is empty when
+        // freezing for the owner itself
         ContractType::FreezeBalanceContract => {
             extract_param!(protocol::FreezeBalanceContract, parameter, receiver_address)
         }
@@ -236,13 +269,40 @@ pub fn extract_to_address(contract_type: i32, parameter: &Any) -> Option<Vec<u8>
 /// The address bytes must already include the Tron prefix (0x41).
 pub fn tron_address_to_base58(address: &[u8]) -> String {
     let hash1 = Sha256::digest(address);
-    let hash2 = Sha256::digest(&hash1);
+    let hash2 = Sha256::digest(hash1);
     let checksum = &hash2[0..4];
     let mut payload = address.to_vec();
     payload.extend_from_slice(checksum);
     bs58::encode(payload).into_string()
 }
 
+/// Converts a Base58Check-encoded address back to the Tron address bytes (with the 0x41 prefix).
+/// Returns `None` when the string is not valid Base58 or its checksum does not match.
+pub fn base58_to_tron_address(address: &str) -> Option<Vec<u8>> {
+    let mut payload = bs58::decode(address).into_vec().ok()?;
+    if payload.len() < 4 {
+        return None;
+    }
+    let checksum = payload.split_off(payload.len() - 4);
+    let hash = Sha256::digest(Sha256::digest(&payload));
+    (hash[0..4] == checksum[..]).then_some(payload)
+}
+
+/// Converts an EVM-style address, as found in event logs and ABI encoded words, to a Base58Check-encoded string.
+/// Only the last 20 bytes are kept and the Tron prefix (0x41) is added back.
+pub fn evm_address_to_base58(address: &[u8]) -> String {
+    let start = address.len().saturating_sub(20);
+    let mut tron_address = Vec::with_capacity(21);
+    tron_address.push(TRON_ADDRESS_PREFIX);
+    tron_address.extend_from_slice(&address[start..]);
+    tron_address_to_base58(&tron_address)
+}
+
+/// Decodes a big-endian ABI encoded uint256 word into its decimal string representation.
+pub fn uint256_to_decimal(word: &[u8]) -> String {
+    BigUint::from_bytes_be(word).to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -519,8 +579,10 @@ mod tests {
     #[test]
     fn extract_shielded_transfer_contract() {
         let owner = vec![7, 7, 7, 7];
-        let mut contract = protocol::ShieldedTransferContract::default();
-        contract.transparent_from_address = owner.clone();
+        let contract = protocol::ShieldedTransferContract {
+            transparent_from_address: owner.clone(),
+            ..Default::default()
+        };
         let any = Any {
             type_url: "type.googleapis.com/protocol.ShieldedTransferContract".to_string(),
             value: contract.encode_to_vec(),
@@ -549,6 +611,40 @@ mod tests {
         assert_eq!(result, None);
     }
 
+    #[test]
+    fn decoded_contracts_filters_by_type() {
+        let transfer = protocol::TransferContract {
+            owner_address: vec![1, 2, 3],
+            to_address: vec![4, 5, 6],
+            amount: 42,
+        };
+        let transaction = Transaction {
+            contracts: vec![
+                protocol::transaction::Contract {
+                    r#type: ContractType::TransferContract as i32,
+                    parameter: Some(Any {
+                        type_url: "type.googleapis.com/protocol.TransferContract".to_string(),
+                        value: transfer.encode_to_vec(),
+                    }),
+                    ..Default::default()
+                },
+                protocol::transaction::Contract {
+                    r#type: ContractType::TriggerSmartContract as i32,
+                    parameter: Some(Any {
+                        type_url: "type.googleapis.com/protocol.TriggerSmartContract".to_string(),
+                        value: vec![],
+                    }),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let decoded: Vec<protocol::TransferContract> =
+            decoded_contracts(&transaction, ContractType::TransferContract).collect();
+        assert_eq!(decoded, vec![transfer]);
+    }
+
     #[test]
     fn test_tron_address_to_base58() {
         // Example: QVeYR7tyCAZ4qm6OjPuRnxjT105C (base64) => THxNDMy3y9NP7Bfat9CTKmZ4PFfj1v4gWa
@@ -561,6 +657,52 @@ mod tests {
         assert_eq!(addr, expected);
     }
 
+    #[test]
+    fn test_base58_to_tron_address() {
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode("QVeYR7tyCAZ4qm6OjPuRnxjT105C")
+            .unwrap();
+        assert_eq!(
+            base58_to_tron_address("THxNDMy3y9NP7Bfat9CTKmZ4PFfj1v4gWa"),
+            Some(bytes)
+        );
+        // Last character altered, the checksum no longer matches
+        assert_eq!(
+            base58_to_tron_address("THxNDMy3y9NP7Bfat9CTKmZ4PFfj1v4gWb"),
+            None
+        );
+        assert_eq!(base58_to_tron_address("0OIl"), None);
+    }
+
+    #[test]
+    fn test_evm_address_to_base58() {
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode("QVeYR7tyCAZ4qm6OjPuRnxjT105C")
+            .unwrap();
+        let expected = "THxNDMy3y9NP7Bfat9CTKmZ4PFfj1v4gWa";
+
+        // Raw 20 bytes log address
+        assert_eq!(evm_address_to_base58(&bytes[1..]), expected);
+        // Left padded 32 bytes topic
+        let mut word = vec![0u8; 12];
+        word.extend_from_slice(&bytes[1..]);
+        assert_eq!(evm_address_to_base58(&word), expected);
+        // Already prefixed address
+        assert_eq!(evm_address_to_base58(&bytes), expected);
+    }
+
+    #[test]
+    fn test_uint256_to_decimal() {
+        let mut word = [0u8; 32];
+        word[30] = 0x01;
+        word[31] = 0x00;
+        assert_eq!(uint256_to_decimal(&word), "256");
+        assert_eq!(
+            uint256_to_decimal(&[0xff; 32]),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+    }
+
     test_to_extract!(
         extract_to_transfer_contract,
         ContractType::TransferContract,
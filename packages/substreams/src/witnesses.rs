@@ -0,0 +1,464 @@
+use crate::pb::protocol;
+use crate::pb::protocol::transaction::contract::ContractType;
+use crate::pb::sf::substreams::tron::v1::{
+    reward_event, BlockProduction, MissedSlot, RewardEvent, RewardEvents, Transactions, VoteEvent,
+    VoteEvents, VoterVotes, WitnessRanking, WitnessVote,
+};
+use crate::pb::sf::substreams::v1::Clock;
+use crate::pb::sf::tron::r#type::v1::Block;
+use crate::utils::{
+    base58_to_tron_address, decode_param, decoded_contracts, tron_address_to_base58,
+};
+use std::collections::BTreeSet;
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::store::{
+    Appender, DeltaInt64, DeltaProto, Deltas, StoreAdd, StoreAddInt64, StoreAppend, StoreGet,
+    StoreGetArray, StoreGetInt64, StoreGetString, StoreNew, StoreSet, StoreSetInt64, StoreSetProto,
+    StoreSetString,
+};
+
+/// Length of a maintenance period, at the end of which votes are counted and witnesses re-elected.
+pub const MAINTENANCE_INTERVAL_SECONDS: i64 = 6 * 60 * 60;
+/// Number of top ranked witnesses elected as super representatives.
+const ACTIVE_WITNESS_COUNT: usize = 27;
+/// Interval between two block slots.
+const BLOCK_INTERVAL_SECONDS: i64 = 3;
+/// Slots skipped by the protocol right after a maintenance block.
+const MAINTENANCE_SKIP_SLOTS: i64 = 2;
+
+const WITNESSES_KEY: &str = "witnesses";
+const BLOCK_TIME_KEY: &str = "block_time";
+const MAINTENANCE_KEY: &str = "maintenance";
+
+/// Emits every change of a vote allocation: set by a `VoteWitnessContract`, or cleared by a Stake 1.0
+/// `UnfreezeBalanceContract`. Under the new resource model (`getAllowNewResourceModel`), and for
+/// every Stake 2.0 `UnfreezeBalanceV2Contract`, java-tron only clears the votes when the remaining
+/// TRON power of the account no longer covers them. That power is not known from the stream, so
+/// Stake 1.0 unfreezes are always taken as clearing the votes, as before the new resource model,
+/// and Stake 2.0 unfreezes as keeping them.
+#[substreams::handlers::map]
+fn map_vote_events(transactions: Transactions) -> Result<VoteEvents, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+    let mut events = Vec::new();
+
+    for transaction in &transactions.transactions {
+        for contract in decoded_contracts::<protocol::VoteWitnessContract>(
+            transaction,
+            ContractType::VoteWitnessContract,
+        ) {
+            events.push(VoteEvent {
+                txid: hex::encode(&transaction.txid),
+                block_number: clock.number,
+                block_time: clock.timestamp,
+                voter: tron_address_to_base58(&contract.owner_address),
+                votes: contract
+                    .votes
+                    .iter()
+                    .map(|vote| WitnessVote {
+                        witness: tron_address_to_base58(&vote.vote_address),
+                        count: vote.vote_count,
+                    })
+                    .collect(),
+                cleared: false,
+            });
+        }
+
+        for contract in decoded_contracts::<protocol::UnfreezeBalanceContract>(
+            transaction,
+            ContractType::UnfreezeBalanceContract,
+        ) {
+            events.push(VoteEvent {
+                txid: hex::encode(&transaction.txid),
+                block_number: clock.number,
+                block_time: clock.timestamp,
+                voter: tron_address_to_base58(&contract.owner_address),
+                votes: Vec::new(),
+                cleared: true,
+            });
+        }
+    }
+
+    Ok(VoteEvents {
+        events,
+        clock: Some(clock),
+    })
+}
+
+/// Keeps the current vote allocation of every voter. A new vote replaces the previous allocation as a
+/// whole, a cleared allocation is kept empty so that its deltas take the previous votes back.
+#[substreams::handlers::store]
+fn store_voter_votes(events: VoteEvents, store: StoreSetProto<VoterVotes>) {
+    for (ordinal, event) in events.events.into_iter().enumerate() {
+        store.set(
+            ordinal as u64,
+            voter_key(&event.voter),
+            &VoterVotes {
+                voter: event.voter,
+                votes: event.votes,
+                block_number: event.block_number,
+                txid: event.txid,
+            },
+        );
+    }
+}
+
+/// Keeps the vote count of every witness, moving the votes of a replaced allocation to the new one.
+#[substreams::handlers::store]
+fn store_witness_votes(votes: Deltas<DeltaProto<VoterVotes>>, store: StoreAddInt64) {
+    for delta in votes.deltas {
+        for vote in &delta.old_value.votes {
+            store.add(delta.ordinal, witness_key(&vote.witness), -vote.count);
+        }
+        for vote in &delta.new_value.votes {
+            store.add(delta.ordinal, witness_key(&vote.witness), vote.count);
+        }
+    }
+}
+
+/// Keeps the list of witnesses that ever received votes, each appended once on its first vote.
+#[substreams::handlers::store]
+fn store_witnesses(votes: Deltas<DeltaInt64>, store: StoreAppend<String>) {
+    for delta in votes.deltas {
+        if delta.operation != Operation::Create {
+            continue;
+        }
+        if let Some(witness) = delta.key.strip_prefix("witness:") {
+            store.append(delta.ordinal, WITNESSES_KEY, witness.to_string());
+        }
+    }
+}
+
+/// Keeps the url of every witness, as set by `WitnessCreateContract` and `WitnessUpdateContract`.
+#[substreams::handlers::store]
+fn store_witness_urls(transactions: Transactions, store: StoreSetString) {
+    let mut ordinal = 0;
+
+    for transaction in &transactions.transactions {
+        for contract in decoded_contracts::<protocol::WitnessCreateContract>(
+            transaction,
+            ContractType::WitnessCreateContract,
+        ) {
+            store.set(
+                ordinal,
+                witness_key(&tron_address_to_base58(&contract.owner_address)),
+                &String::from_utf8_lossy(&contract.url).into_owned(),
+            );
+            ordinal += 1;
+        }
+
+        for contract in decoded_contracts::<protocol::WitnessUpdateContract>(
+            transaction,
+            ContractType::WitnessUpdateContract,
+        ) {
+            store.set(
+                ordinal,
+                witness_key(&tron_address_to_base58(&contract.owner_address)),
+                &String::from_utf8_lossy(&contract.update_url).into_owned(),
+            );
+            ordinal += 1;
+        }
+    }
+}
+
+/// Keeps the time of the latest block, so that its deltas carry the time of the previous block.
+#[substreams::handlers::store]
+fn store_block_time(clock: Clock, store: StoreSetInt64) {
+    let seconds = clock.timestamp.map_or(0, |timestamp| timestamp.seconds);
+    store.set(0, BLOCK_TIME_KEY, &seconds);
+}
+
+/// Keeps whether the latest block performed the maintenance, being the first block at or after a
+/// maintenance boundary, so that its deltas tell whether the previous block did.
+#[substreams::handlers::store]
+fn store_maintenance(block_times: Deltas<DeltaInt64>, store: StoreSetInt64) {
+    let maintenance = block_times
+        .deltas
+        .last()
+        // The first block processed has no previous block to compare with
+        .filter(|delta| delta.old_value > 0)
+        .and_then(|delta| maintenance_boundary(delta.old_value, delta.new_value))
+        .is_some();
+    store.set(0, MAINTENANCE_KEY, &i64::from(maintenance));
+}
+
+#[substreams::handlers::map]
+fn map_witness_ranking(
+    clock: Clock,
+    block_times: Deltas<DeltaInt64>,
+    witnesses: StoreGetArray<String>,
+    votes: StoreGetInt64,
+    urls: StoreGetString,
+    production: StoreGetInt64,
+) -> Result<WitnessRanking, substreams::errors::Error> {
+    let Some(maintenance_time) = block_times
+        .deltas
+        .last()
+        .and_then(|delta| maintenance_boundary(delta.old_value, delta.new_value))
+    else {
+        return Ok(WitnessRanking {
+            clock: Some(clock),
+            ..Default::default()
+        });
+    };
+
+    let addresses: BTreeSet<String> = witnesses
+        .get_last(WITNESSES_KEY)
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+
+    let mut ranking: Vec<protocol::Witness> = addresses
+        .iter()
+        .filter_map(|witness| {
+            let key = witness_key(witness);
+            Some(protocol::Witness {
+                address: base58_to_tron_address(witness)?,
+                vote_count: votes.get_last(&key).unwrap_or_default(),
+                url: urls.get_last(&key).unwrap_or_default(),
+                total_produced: production
+                    .get_last(format!("total_produced:{}", witness))
+                    .unwrap_or_default(),
+                total_missed: production
+                    .get_last(format!("total_missed:{}", witness))
+                    .unwrap_or_default(),
+                ..Default::default()
+            })
+        })
+        .collect();
+
+    // Ties are broken on the address to keep the ranking deterministic
+    ranking.sort_by(|a, b| {
+        b.vote_count
+            .cmp(&a.vote_count)
+            .then_with(|| a.address.cmp(&b.address))
+    });
+    for witness in ranking.iter_mut().take(ACTIVE_WITNESS_COUNT) {
+        witness.is_jobs = true;
+    }
+
+    Ok(WitnessRanking {
+        maintenance_time: Some(prost_types::Timestamp {
+            seconds: maintenance_time,
+            nanos: 0,
+        }),
+        witnesses: ranking,
+        clock: Some(clock),
+    })
+}
+
+/// Keeps the witness that produced the latest block at every position of the production schedule.
+#[substreams::handlers::store]
+fn store_witness_schedule(block: Block, store: StoreSetString) {
+    let Some(header) = block.header else {
+        return;
+    };
+
+    let slot = header.timestamp / 1000 / BLOCK_INTERVAL_SECONDS;
+    store.set(
+        0,
+        schedule_key(slot),
+        &tron_address_to_base58(&header.witness_address),
+    );
+}
+
+#[substreams::handlers::map]
+fn map_block_production(
+    clock: Clock,
+    block: Block,
+    block_times: Deltas<DeltaInt64>,
+    maintenance: Deltas<DeltaInt64>,
+    schedule: StoreGetString,
+) -> Result<BlockProduction, substreams::errors::Error> {
+    let Some(header) = block.header else {
+        return Ok(BlockProduction {
+            clock: Some(clock),
+            ..Default::default()
+        });
+    };
+
+    let slot = header.timestamp / 1000 / BLOCK_INTERVAL_SECONDS;
+    let previous_maintenance = maintenance
+        .deltas
+        .last()
+        .is_some_and(|delta| delta.old_value == 1);
+    let missed_slots = match block_times.deltas.last() {
+        // Nothing to compare with on the first block processed
+        Some(delta) if delta.old_value > 0 => {
+            missed_slots(delta.old_value, previous_maintenance, slot)
+                .map(|missed| MissedSlot {
+                    slot: missed,
+                    witness: schedule.get_last(schedule_key(missed)).unwrap_or_default(),
+                })
+                .collect()
+        }
+        _ => Vec::new(),
+    };
+
+    Ok(BlockProduction {
+        witness: tron_address_to_base58(&header.witness_address),
+        slot,
+        missed_slots,
+        clock: Some(clock),
+    })
+}
+
+/// Keeps the number of blocks produced and slots missed by every witness.
+#[substreams::handlers::store]
+fn store_witness_production(production: BlockProduction, store: StoreAddInt64) {
+    if production.witness.is_empty() {
+        return;
+    }
+
+    store.add(0, format!("total_produced:{}", production.witness), 1);
+    for missed in production.missed_slots {
+        if !missed.witness.is_empty() {
+            store.add(0, format!("total_missed:{}", missed.witness), 1);
+        }
+    }
+}
+
+#[substreams::handlers::map]
+fn map_reward_events(
+    transactions: Transactions,
+) -> Result<RewardEvents, substreams::errors::Error> {
+    let clock = transactions.clock.unwrap_or_default();
+    let mut events = Vec::new();
+
+    for transaction in &transactions.transactions {
+        let withdraw_amount = transaction
+            .info
+            .as_ref()
+            .map_or(0, |info| info.withdraw_amount);
+
+        for contract in &transaction.contracts {
+            let Some(ref parameter) = contract.parameter else {
+                continue;
+            };
+
+            let decoded = match ContractType::try_from(contract.r#type) {
+                Ok(ContractType::WithdrawBalanceContract) => {
+                    decode_param::<protocol::WithdrawBalanceContract>(parameter).map(|c| {
+                        let event = reward_event::Event::Withdraw(reward_event::Withdraw {
+                            amount: withdraw_amount,
+                        });
+                        (c.owner_address, event)
+                    })
+                }
+                Ok(ContractType::UpdateBrokerageContract) => {
+                    decode_param::<protocol::UpdateBrokerageContract>(parameter).map(|c| {
+                        let event =
+                            reward_event::Event::BrokerageUpdate(reward_event::BrokerageUpdate {
+                                brokerage: c.brokerage,
+                            });
+                        (c.owner_address, event)
+                    })
+                }
+                _ => None,
+            };
+
+            if let Some((owner_address, event)) = decoded {
+                events.push(RewardEvent {
+                    txid: hex::encode(&transaction.txid),
+                    block_number: clock.number,
+                    block_time: clock.timestamp,
+                    owner: tron_address_to_base58(&owner_address),
+                    event: Some(event),
+                });
+            }
+        }
+    }
+
+    Ok(RewardEvents {
+        events,
+        clock: Some(clock),
+    })
+}
+
+/// Keeps the current brokerage of every witness, as a percentage.
+#[substreams::handlers::store]
+fn store_witness_brokerage(events: RewardEvents, store: StoreSetInt64) {
+    for (ordinal, event) in events.events.iter().enumerate() {
+        if let Some(reward_event::Event::BrokerageUpdate(update)) = event.event {
+            store.set(
+                ordinal as u64,
+                witness_key(&event.owner),
+                &(update.brokerage as i64),
+            );
+        }
+    }
+}
+
+fn voter_key(voter: &str) -> String {
+    format!("votes:{}", voter)
+}
+
+fn witness_key(witness: &str) -> String {
+    format!("witness:{}", witness)
+}
+
+/// Schedule positions repeat every `ACTIVE_WITNESS_COUNT` slots, counted from the genesis (time 0).
+fn schedule_key(slot: i64) -> String {
+    format!("slot:{}", slot.rem_euclid(ACTIVE_WITNESS_COUNT as i64))
+}
+
+/// Returns the slots left empty between the previous block time, in seconds, and the current slot.
+/// The slots skipped after the block that performed the maintenance are not missed.
+fn missed_slots(previous_time: i64, previous_maintenance: bool, slot: i64) -> std::ops::Range<i64> {
+    let mut first = previous_time / BLOCK_INTERVAL_SECONDS + 1;
+    if previous_maintenance {
+        first += MAINTENANCE_SKIP_SLOTS;
+    }
+    first..slot.max(first)
+}
+
+/// Returns the maintenance boundary crossed between two block times, in seconds, if any.
+/// Boundaries are aligned on multiples of the maintenance interval since the epoch.
+pub fn maintenance_boundary(previous: i64, current: i64) -> Option<i64> {
+    let boundary = current - current.rem_euclid(MAINTENANCE_INTERVAL_SECONDS);
+    (previous < boundary).then_some(boundary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missed_slots_between_blocks() {
+        // 2024-01-01T06:00:00Z
+        let boundary = 1_704_088_800;
+        let slot = |time: i64| time / BLOCK_INTERVAL_SECONDS;
+
+        assert!(missed_slots(boundary + 3, false, slot(boundary + 6)).is_empty());
+        assert_eq!(
+            missed_slots(boundary + 3, false, slot(boundary + 12)),
+            slot(boundary + 6)..slot(boundary + 12)
+        );
+        // Maintenance block, followed by the skipped slots
+        assert!(missed_slots(boundary, true, slot(boundary + 9)).is_empty());
+        assert_eq!(
+            missed_slots(boundary, true, slot(boundary + 12)),
+            slot(boundary + 9)..slot(boundary + 12)
+        );
+        // Maintenance performed by the first block after a boundary left empty
+        assert!(missed_slots(boundary + 6, true, slot(boundary + 15)).is_empty());
+        assert_eq!(
+            missed_slots(boundary + 6, false, slot(boundary + 15)),
+            slot(boundary + 9)..slot(boundary + 15)
+        );
+    }
+
+    #[test]
+    fn maintenance_boundary_on_first_block_of_period() {
+        // 2024-01-01T06:00:00Z
+        let boundary = 1_704_088_800;
+
+        assert_eq!(maintenance_boundary(boundary - 3, boundary), Some(boundary));
+        // Missed slots right before the boundary
+        assert_eq!(
+            maintenance_boundary(boundary - 9, boundary + 6),
+            Some(boundary)
+        );
+        assert_eq!(maintenance_boundary(boundary, boundary + 3), None);
+        assert_eq!(maintenance_boundary(boundary - 6, boundary - 3), None);
+    }
+}